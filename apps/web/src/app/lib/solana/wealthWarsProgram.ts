@@ -89,6 +89,39 @@ export class WealthWarsProgram {
     return pda;
   }
 
+  /**
+   * Get the PDA for the global game config
+   */
+  getConfigPDA(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('config')],
+      WEALTH_WARS_PROGRAM_ID
+    );
+    return pda;
+  }
+
+  /**
+   * Get the PDA for the global work progression tables
+   */
+  getWorkConfigPDA(): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('work_config')],
+      WEALTH_WARS_PROGRAM_ID
+    );
+    return pda;
+  }
+
+  /**
+   * Get the PDA for a business catalog entry
+   */
+  getBusinessTypePDA(businessId: number): PublicKey {
+    const [pda] = PublicKey.findProgramAddressSync(
+      [Buffer.from('business'), Buffer.from([businessId])],
+      WEALTH_WARS_PROGRAM_ID
+    );
+    return pda;
+  }
+
   /**
    * Initialize a new player (call once per wallet)
    */
@@ -119,6 +152,11 @@ export class WealthWarsProgram {
             isSigner: true,
             isWritable: true,
           },
+          {
+            pubkey: this.getConfigPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
           {
             pubkey: SystemProgram.programId,
             isSigner: false,
@@ -164,6 +202,7 @@ export class WealthWarsProgram {
           return { success: false, error: 'Failed to initialize player' };
         }
       }
+      const activeBusinessSlots = playerState?.activeBusinessSlots ?? [];
 
       // Create work instruction
       const instruction = new TransactionInstruction({
@@ -173,11 +212,87 @@ export class WealthWarsProgram {
             isSigner: false,
             isWritable: true,
           },
+          {
+            pubkey: this.wallet.publicKey,
+            isSigner: false,
+            isWritable: false,
+          },
           {
             pubkey: this.wallet.publicKey,
             isSigner: true,
             isWritable: true,
           },
+          {
+            pubkey: this.getConfigPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: this.getWorkConfigPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+          // Optional attestor, instructions sysvar, session key, season
+          // stats, season pass, achievements and the SPL credits accounts
+          // (credits mint config, credits mint, owner credits account, token
+          // program), unused when the wallet signs without attestation and
+          // earns ledger credits
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          // Catalog entry of every active business, in slot order
+          ...activeBusinessSlots.map((businessId) => ({
+            pubkey: this.getBusinessTypePDA(businessId),
+            isSigner: false,
+            isWritable: false,
+          })),
         ],
         programId: WEALTH_WARS_PROGRAM_ID,
         data: Buffer.from([181, 175, 109, 31, 13, 152, 155, 237]), // do_work discriminator (placeholder)
@@ -231,6 +346,7 @@ export class WealthWarsProgram {
       }
 
       const playerPDA = this.getPlayerPDA(this.wallet.publicKey);
+      const businessesOwned = (await this.getPlayerState())?.businessesOwned ?? [];
 
       // Create purchase instruction
       const instruction = new TransactionInstruction({
@@ -245,6 +361,56 @@ export class WealthWarsProgram {
             isSigner: true,
             isWritable: true,
           },
+          {
+            pubkey: this.getConfigPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: this.getBusinessTypePDA(businessId),
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: this.getWorkConfigPDA(),
+            isSigner: false,
+            isWritable: false,
+          },
+          // Optional achievements and SPL credits accounts (credits mint
+          // config, credits mint, owner credits account, token program),
+          // unused when paying with ledger credits
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          {
+            pubkey: WEALTH_WARS_PROGRAM_ID,
+            isSigner: false,
+            isWritable: false,
+          },
+          // Catalog entry of every owned business, in ownership order, so
+          // pending income is settled before the purchase
+          ...businessesOwned.map((ownedId) => ({
+            pubkey: this.getBusinessTypePDA(ownedId),
+            isSigner: false,
+            isWritable: false,
+          })),
         ],
         programId: WEALTH_WARS_PROGRAM_ID,
         data: Buffer.concat([
@@ -283,21 +449,47 @@ export class WealthWarsProgram {
       
       if (!accountInfo) return null;
 
-      // Parse account data (simplified - would use proper deserialization)
-      // For now, return mock data structure
+      // Decode the leading PlayerState fields: 8-byte discriminator, then
+      // version, owner and the fixed-size counters ahead of the two vectors
+      const data = accountInfo.data;
+      let offset = 8 + 1;
+      const readBytes = (len: number) => {
+        const bytes = data.subarray(offset, offset + len);
+        offset += len;
+        return bytes;
+      };
+      const readU8 = () => readBytes(1)[0];
+      const readU32 = () => readBytes(4).readUInt32LE(0);
+      const readU64 = () => new BN(readBytes(8), 'le');
+      const readVec = () => Array.from(readBytes(readU32()));
+
+      const owner = new PublicKey(readBytes(32));
+      const lastWorkTimestamp = readU64();
+      const streakCount = readU32();
+      const workFrequencyLevel = readU8();
+      const totalWorkActions = readU64();
+      const credits = readU64();
+      const wealthTokens = readU64();
+      const businessesOwned = readVec();
+      const activeBusinessSlots = readVec();
+      const lastStreakCheck = readU64();
+      const cooldownHours = readU8();
+      readU64(); // last_defend_ts
+      const bump = readU8();
+
       return {
-        owner: this.wallet.publicKey,
-        lastWorkTimestamp: new BN(0),
-        streakCount: 0,
-        workFrequencyLevel: 0,
-        totalWorkActions: new BN(0),
-        credits: new BN(1000),
-        wealthTokens: new BN(0),
-        businessesOwned: [],
-        activeBusinessSlots: [],
-        lastStreakCheck: new BN(0),
-        cooldownHours: 24,
-        bump: 0,
+        owner,
+        lastWorkTimestamp,
+        streakCount,
+        workFrequencyLevel,
+        totalWorkActions,
+        credits,
+        wealthTokens,
+        businessesOwned,
+        activeBusinessSlots,
+        lastStreakCheck,
+        cooldownHours,
+        bump,
       };
       
     } catch (error) {
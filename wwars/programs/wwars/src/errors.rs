@@ -30,6 +30,18 @@ pub enum ErrorCode {
     InvalidBusinessId,
     #[msg("Maximum slots reached")]
     MaxSlotsReached,
+    #[msg("Business already active")]
+    AlreadyActive,
+    #[msg("Business not active")]
+    NotActive,
+    #[msg("Business is not available for purchase")]
+    BusinessDisabled,
+    #[msg("Work level too low")]
+    LevelTooLow,
+    #[msg("Business type accounts do not match the player's businesses")]
+    BusinessTypeMismatch,
+    #[msg("Business already at max level")]
+    MaxLevelReached,
     #[msg("Treasury is paused")]
     TreasuryPaused,
     #[msg("Fee too high (max 10%)")]
@@ -48,4 +60,62 @@ pub enum ErrorCode {
     PoolNotReady,
     #[msg("Insufficient seed liquidity")]
     InsufficientSeed,
+    #[msg("Guardian can only add pause flags")]
+    GuardianCannotUnpause,
+    #[msg("Revenue splits must sum to 10000 bps")]
+    InvalidRevenueSplit,
+    #[msg("Burn share too high (max 100%)")]
+    BurnTooHigh,
+    #[msg("Account already migrated")]
+    AlreadyMigrated,
+    #[msg("Business NFTs are disabled")]
+    BusinessNftDisabled,
+    #[msg("Holding is already tokenized")]
+    AlreadyTokenized,
+    #[msg("Work tables must be monotonic across levels")]
+    InvalidWorkConfig,
+    #[msg("Streak freezes are not sold for this currency")]
+    StreakFreezeNotForSale,
+    #[msg("Streak freeze limit reached")]
+    StreakFreezeLimit,
+    #[msg("Missing or invalid work attestation")]
+    AttestationRequired,
+    #[msg("Session key has expired")]
+    SessionExpired,
+    #[msg("Session key does not allow this action")]
+    SessionActionNotAllowed,
+    #[msg("Session key spend limit exceeded")]
+    SessionLimitExceeded,
+    #[msg("Credit transfers are disabled")]
+    CreditTransfersDisabled,
+    #[msg("Account too young to transfer credits")]
+    AccountTooYoung,
+    #[msg("Daily credit transfer limit exceeded")]
+    DailyTransferLimit,
+    #[msg("Credits mint is disabled")]
+    CreditsMintDisabled,
+    #[msg("No WEALTH token account or escrow provided")]
+    WealthSourceMissing,
+    #[msg("Invalid season")]
+    InvalidSeason,
+    #[msg("Season has not started")]
+    SeasonNotStarted,
+    #[msg("Premium pass is not for sale")]
+    PremiumPassNotForSale,
+    #[msg("Premium pass already owned")]
+    PremiumPassOwned,
+    #[msg("Season pass level not reached")]
+    PassLevelNotReached,
+    #[msg("Season pass reward already claimed")]
+    PassRewardClaimed,
+    #[msg("Credits mint accounts are required when the quote mint is the credits mint")]
+    CreditsMintAccountsMissing,
+    #[msg("Player has not been rolled into the current season")]
+    SeasonCarryOverPending,
+    #[msg("Not all of the player's holdings were passed")]
+    HoldingsMissing,
+    #[msg("Token account already has a delegate")]
+    TokenDelegateInUse,
+    #[msg("SPL credits exceed what this player was paid out")]
+    BridgedCreditsExceeded,
 }
@@ -1,10 +1,38 @@
 use anchor_lang::prelude::*;
+use crate::state::{CarryOverRules, LevelDecayMode, WorkTables};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum SpendAction {
+    BuyAsset,
+    QueueUpgrade,
+    Defend,
+    Takeover,
+    StreakFreeze,
+    PremiumPass,
+}
 
 #[event]
 pub struct PlayerJoined {
     pub player: Pubkey,
 }
 
+#[event]
+pub struct PlayerMigrated {
+    pub player: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub merged_legacy_player: bool,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct AssetBought {
     pub player: Pubkey,
@@ -45,10 +73,62 @@ pub struct TakenOver {
     pub cost: u64,
 }
 
+#[event]
+pub struct HoldingTokenized {
+    pub player: Pubkey,
+    pub holding: Pubkey,
+    pub class: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoldingNftDetached {
+    pub holding: Pubkey,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct HoldingYieldClaimed {
+    pub player: Pubkey,
+    pub class: Pubkey,
+    pub level: u16,
+    pub amount: u64,
+    pub accrued_secs: i64,
+    pub timestamp: i64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct WorkRewardBreakdown {
+    pub base: u64,
+    pub level_bonus: u64,
+    pub business_multiplier_bonus: u64,
+    pub set_bonus: u64,
+    pub business_flat_bonus: u64,
+    pub streak_bonus: u64,
+}
+
+impl WorkRewardBreakdown {
+    pub fn total(&self) -> Result<u64> {
+        [
+            self.level_bonus,
+            self.business_multiplier_bonus,
+            self.set_bonus,
+            self.business_flat_bonus,
+            self.streak_bonus,
+        ]
+        .iter()
+        .try_fold(self.base, |acc, x| acc.checked_add(*x))
+        .ok_or(crate::errors::ErrorCode::MathOverflow.into())
+    }
+}
+
 #[event]
 pub struct WorkCompleted {
     pub player: Pubkey,
     pub reward: u64,
+    pub breakdown: WorkRewardBreakdown,
     pub new_streak: u32,
     pub new_level: u8,
     pub timestamp: i64,
@@ -62,10 +142,306 @@ pub struct BusinessPurchased {
     pub timestamp: i64,
 }
 
+#[event]
+pub struct BusinessTypeUpdated {
+    pub business_id: u8,
+    pub cost: u64,
+    pub work_multiplier_bps: u16,
+    pub flat_bonus: u64,
+    pub set_id: u8,
+    pub set_bonus_bps: u16,
+    pub passive_income_per_hour: u64,
+    pub required_level: u8,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ActiveBusinessesChanged {
+    pub player: Pubkey,
+    pub activated: Option<u8>,
+    pub deactivated: Vec<u8>,
+    pub active_business_slots: Vec<u8>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessUpgraded {
+    pub player: Pubkey,
+    pub business_id: u8,
+    pub new_level: u8,
+    pub cost: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessIncomeClaimed {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub accrued_secs: i64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessSold {
+    pub player: Pubkey,
+    pub business_id: u8,
+    pub refund: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessListed {
+    pub seller: Pubkey,
+    pub business_id: u8,
+    pub level: u8,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessListingCancelled {
+    pub seller: Pubkey,
+    pub business_id: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessTraded {
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub business_id: u8,
+    pub level: u8,
+    pub price: u64,
+    pub fee: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub id: u32,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub rules: CarryOverRules,
+}
+
+#[event]
+pub struct SeasonStarted {
+    pub id: u32,
+    pub previous: u32,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonCarryOver {
+    pub player: Pubkey,
+    pub season_id: u32,
+    pub credits_before: u64,
+    pub credits_after: u64,
+    pub level_before: u8,
+    pub level_after: u8,
+}
+
+#[event]
+pub struct SeasonJoined {
+    pub player: Pubkey,
+    pub season_id: u32,
+}
+
+#[event]
+pub struct RankUpdated {
+    pub season_id: u32,
+    pub player: Pubkey,
+    pub net_worth: u64,
+    pub rank: Option<u8>,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AchievementUnlocked {
+    pub player: Pubkey,
+    pub achievement: u8,
+    pub bonus_credits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SeasonPassConfigUpdated {
+    pub premium_price: u64,
+    pub free_reward: u64,
+    pub premium_reward: u64,
+}
+
+#[event]
+pub struct SeasonPassOpened {
+    pub player: Pubkey,
+    pub season_id: u32,
+}
+
+#[event]
+pub struct PremiumPassPurchased {
+    pub player: Pubkey,
+    pub season_id: u32,
+    pub price: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct PassRewardClaimed {
+    pub player: Pubkey,
+    pub season_id: u32,
+    pub level: u8,
+    pub free_amount: u64,
+    pub premium_amount: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WealthDeposited {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub escrowed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WealthWithdrawn {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub escrowed: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsMintInitialized {
+    pub mint: Pubkey,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct CreditsMintConfigUpdated {
+    pub enabled: bool,
+}
+
+#[event]
+pub struct CreditsDeposited {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub credits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsWithdrawn {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub credits: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessNftConfigUpdated {
+    pub enabled: bool,
+    pub base_uri: String,
+}
+
+#[event]
+pub struct BusinessWrapped {
+    pub player: Pubkey,
+    pub business_id: u8,
+    pub level: u8,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessUnwrapped {
+    pub player: Pubkey,
+    pub business_id: u8,
+    pub level: u8,
+    pub mint: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct BusinessNftIncomeClaimed {
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub business_id: u8,
+    pub amount: u64,
+    pub accrued_secs: i64,
+    pub timestamp: i64,
+}
+
 #[event]
 pub struct StreakBroken {
     pub player: Pubkey,
     pub old_streak: u32,
+    pub new_streak: u32,
+    pub old_level: u8,
+    pub new_level: u8,
+    pub freezes_used: u8,             // Non-zero when freezes kept the streak alive
+    pub freezes_remaining: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreakFreezesPurchased {
+    pub player: Pubkey,
+    pub quantity: u8,
+    pub credits_paid: u64,
+    pub wealth_paid: u64,
+    pub freezes_held: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct LevelDecayConfigUpdated {
+    pub mode: LevelDecayMode,
+    pub activity_decay_bps: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct StreakFreezeConfigUpdated {
+    pub price_credits: u64,
+    pub price_wealth: u64,
+    pub max_streak_freezes: u8,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct CreditsTransferred {
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub fee: u64,
+    pub sent_today: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionCreated {
+    pub owner: Pubkey,
+    pub session_signer: Pubkey,
+    pub expires_at: i64,
+    pub allowed_actions: u16,
+    pub max_credits_spend: u64,
+    pub max_wealth_spend: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct SessionRevoked {
+    pub owner: Pubkey,
+    pub session_signer: Pubkey,
+    pub credits_spent: u64,
+    pub wealth_spent: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct WorkConfigUpdated {
+    pub tables: WorkTables,
     pub timestamp: i64,
 }
 
@@ -113,3 +489,40 @@ pub struct TreasuryParamsUpdated {
     pub new_max_trade: u64,
     pub timestamp: i64,
 }
+
+#[event]
+pub struct PauseFlagsUpdated {
+    pub target: Pubkey,     // GameConfig or Treasury account
+    pub authority: Pubkey,
+    pub old_flags: u16,
+    pub new_flags: u16,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevenueDistributed {
+    pub caller: Pubkey,
+    pub total: u64,
+    pub to_rewards: u64,
+    pub to_treasury: u64,
+    pub burned: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct RevenueConfigUpdated {
+    pub rewards_bps: u16,
+    pub treasury_bps: u16,
+    pub burn_bps: u16,
+    pub rewards_vault: Pubkey,
+    pub treasury_destination: Pubkey,
+}
+
+#[event]
+pub struct TokensBurned {
+    pub player: Pubkey,
+    pub action: SpendAction,
+    pub amount: u64,
+    pub total_burned: u64,
+    pub timestamp: i64,
+}
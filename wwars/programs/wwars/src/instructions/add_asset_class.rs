@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::migration::Versioned;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -10,7 +11,7 @@ pub struct AddAssetClass<'info> {
 
     #[account(
         has_one = admin @ ErrorCode::Unauthorized,
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        constraint = !game_config.is_paused(PAUSE_BUY_ASSET) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -53,6 +54,7 @@ pub fn add_asset_class(
     asset_class.defend_cd = params.defend_cd;
     asset_class.base_risk_growth_per_sec = params.base_risk_growth_per_sec;
     asset_class.bump = ctx.bumps.asset_class;
+    asset_class.version = AssetClass::CURRENT_VERSION;
 
     Ok(())
 }
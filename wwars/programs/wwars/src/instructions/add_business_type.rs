@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::BusinessTypeUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(params: AddBusinessTypeParams)]
+pub struct AddBusinessType<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BusinessType::SPACE,
+        seeds = [b"business".as_ref(), &[params.business_id]],
+        bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AddBusinessTypeParams {
+    pub business_id: u8,
+    pub cost: u64,
+    pub name_hash: [u8; 32],
+    pub work_multiplier_bps: u16,
+    pub flat_bonus: u64,
+    pub set_id: u8,
+    pub set_bonus_bps: u16,
+    pub passive_income_per_hour: u64,
+    pub required_level: u8,
+    pub enabled: bool,
+}
+
+pub fn add_business_type(
+    ctx: Context<AddBusinessType>,
+    params: AddBusinessTypeParams,
+) -> Result<()> {
+    require!(params.business_id < MAX_BUSINESS_TYPES, ErrorCode::InvalidBusinessId);
+    require!(params.cost > 0, ErrorCode::ZeroAmount);
+
+    let business_type = &mut ctx.accounts.business_type;
+
+    business_type.business_id = params.business_id;
+    business_type.cost = params.cost;
+    business_type.name_hash = params.name_hash;
+    business_type.work_multiplier_bps = params.work_multiplier_bps;
+    business_type.flat_bonus = params.flat_bonus;
+    business_type.set_id = params.set_id;
+    business_type.set_bonus_bps = params.set_bonus_bps;
+    business_type.passive_income_per_hour = params.passive_income_per_hour;
+    business_type.required_level = params.required_level;
+    business_type.enabled = params.enabled;
+    business_type.bump = ctx.bumps.business_type;
+    business_type.version = BusinessType::CURRENT_VERSION;
+
+    emit!(BusinessTypeUpdated {
+        business_id: business_type.business_id,
+        cost: business_type.cost,
+        work_multiplier_bps: business_type.work_multiplier_bps,
+        flat_bonus: business_type.flat_bonus,
+        set_id: business_type.set_id,
+        set_bonus_bps: business_type.set_bonus_bps,
+        passive_income_per_hour: business_type.passive_income_per_hour,
+        required_level: business_type.required_level,
+        enabled: business_type.enabled,
+    });
+
+    Ok(())
+}
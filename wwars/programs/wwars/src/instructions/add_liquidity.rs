@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint, MintTo};
-use crate::state::Treasury;
+use crate::state::{Treasury, PAUSE_LIQUIDITY};
 use crate::events::LiquidityAdded;
 use crate::errors::ErrorCode;
 
@@ -50,6 +50,8 @@ pub fn add_liquidity(
     let treasury = &ctx.accounts.treasury;
     let clock = Clock::get()?;
     
+    require!(!treasury.is_paused(PAUSE_LIQUIDITY), ErrorCode::TreasuryPaused);
+    
     // Generate treasury PDA seeds for signing
     let treasury_seeds = &[
         b"treasury",
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::{BusinessNft, GameConfig, PlayerState, WorkConfig, PAUSE_BUSINESS};
+use crate::math::authorize_business_nft;
+use crate::errors::ErrorCode;
+use crate::events::ActiveBusinessesChanged;
+
+#[derive(Accounts)]
+pub struct ManageBusinessSlots<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    /// Record of a wrapped business to activate instead of an owned one
+    #[account(
+        seeds = [b"business_nft", business_nft.mint.as_ref()],
+        bump = business_nft.bump
+    )]
+    pub business_nft: Option<Account<'info, BusinessNft>>,
+    
+    /// Owner's token account for the NFT of `business_nft`
+    pub holder_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Checks that the player may put `business_id` in a slot: owned outright,
+/// or held as the NFT of `business_nft`. Returns whether the NFT backs it.
+fn authorize_slot_business(accounts: &ManageBusinessSlots, business_id: u8) -> Result<bool> {
+    let player = &accounts.player_state;
+    if player.businesses_owned.contains(&business_id) {
+        return Ok(false);
+    }
+    let record = accounts.business_nft.as_ref().ok_or(ErrorCode::NotOwned)?;
+    require!(record.business_id == business_id, ErrorCode::NotOwned);
+    authorize_business_nft(record, &player.owner, accounts.holder_nft_account.as_deref())?;
+    Ok(true)
+}
+
+/// Puts an owned business, or one held as an NFT, into a free work slot.
+pub fn activate_business(ctx: Context<ManageBusinessSlots>, business_id: u8) -> Result<()> {
+    let nft = authorize_slot_business(ctx.accounts, business_id)?;
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    require!(!player.active_business_slots.contains(&business_id), ErrorCode::AlreadyActive);
+    
+    let max_slots = ctx.accounts.work_config.max_slots(player.work_frequency_level);
+    require!(
+        player.active_business_slots.len() < max_slots as usize,
+        ErrorCode::MaxSlotsReached
+    );
+    
+    player.active_business_slots.push(business_id);
+    player.set_nft_slot(business_id, nft);
+    
+    emit!(ActiveBusinessesChanged {
+        player: player.owner,
+        activated: Some(business_id),
+        deactivated: Vec::new(),
+        active_business_slots: player.active_business_slots.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+pub fn deactivate_business(ctx: Context<ManageBusinessSlots>, business_id: u8) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    require!(player.active_business_slots.contains(&business_id), ErrorCode::NotActive);
+    
+    player.active_business_slots.retain(|id| *id != business_id);
+    player.set_nft_slot(business_id, false);
+    
+    emit!(ActiveBusinessesChanged {
+        player: player.owner,
+        activated: None,
+        deactivated: vec![business_id],
+        active_business_slots: player.active_business_slots.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+pub fn swap_active_business(
+    ctx: Context<ManageBusinessSlots>,
+    out_business_id: u8,
+    in_business_id: u8,
+) -> Result<()> {
+    let nft = authorize_slot_business(ctx.accounts, in_business_id)?;
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    let slot = player
+        .active_business_slots
+        .iter()
+        .position(|id| *id == out_business_id)
+        .ok_or(ErrorCode::NotActive)?;
+    require!(!player.active_business_slots.contains(&in_business_id), ErrorCode::AlreadyActive);
+    
+    // Swap in place so the slot keeps its position
+    player.active_business_slots[slot] = in_business_id;
+    player.set_nft_slot(out_business_id, false);
+    player.set_nft_slot(in_business_id, nft);
+    
+    emit!(ActiveBusinessesChanged {
+        player: player.owner,
+        activated: Some(in_business_id),
+        deactivated: vec![out_business_id],
+        active_business_slots: player.active_business_slots.clone(),
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
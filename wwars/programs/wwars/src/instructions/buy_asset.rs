@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::math::*;
+use crate::migration::Versioned;
 use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -13,14 +15,16 @@ pub struct BuyAsset<'info> {
 
     #[account(
         mut,
-        seeds = [b"player", buyer.key().as_ref()],
-        bump = player.bump,
-        constraint = player.authority == buyer.key() @ ErrorCode::Unauthorized
+        seeds = [b"player_state", buyer.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == buyer.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
-    pub player: Account<'info, Player>,
+    pub player_state: Account<'info, PlayerState>,
 
     #[account(
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        mut,
+        constraint = !game_config.is_paused(PAUSE_BUY_ASSET) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -44,7 +48,15 @@ pub struct BuyAsset<'info> {
         token::mint = game_config.wealth_mint,
         token::authority = buyer
     )]
-    pub buyer_token_account: Account<'info, TokenAccount>,
+    pub buyer_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -55,8 +67,22 @@ pub struct BuyAsset<'info> {
     )]
     pub treasury_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
     pub system_program: Program<'info, System>,
+
+    /// Current season pass; earns XP while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &season_pass.season_id.to_le_bytes(), buyer.key().as_ref()],
+        bump = season_pass.bump
+    )]
+    pub season_pass: Option<Account<'info, SeasonPass>>,
 }
 
 pub fn buy_asset(ctx: Context<BuyAsset>, _class_id: u64) -> Result<()> {
@@ -80,15 +106,32 @@ pub fn buy_asset(ctx: Context<BuyAsset>, _class_id: u64) -> Result<()> {
         .ok_or(ErrorCode::MathOverflow)?;
     let total_cost = price.checked_add(fee as u64).ok_or(ErrorCode::MathOverflow)?;
 
-    // Transfer tokens
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.buyer_token_account.to_account_info(),
-        to: ctx.accounts.treasury_vault.to_account_info(),
-        authority: ctx.accounts.buyer.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, total_cost)?;
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(total_cost, game_config.burn_bps)?;
+    let vault_amount = total_cost.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.buyer_token_account.as_ref(),
+        ctx.accounts.buyer.to_account_info(),
+        &[],
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        &mut ctx.accounts.player_state,
+        total_cost,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
+
+    if let Some(pass) = ctx.accounts.season_pass.as_deref_mut() {
+        pass.add_xp(XP_BUY_ASSET, clock.unix_timestamp);
+    }
 
     // Initialize holding
     holding.player = ctx.accounts.buyer.key();
@@ -101,6 +144,23 @@ pub fn buy_asset(ctx: Context<BuyAsset>, _class_id: u64) -> Result<()> {
     holding.last_risk_ts = clock.unix_timestamp;
     holding.risk_score = 0;
     holding.bump = ctx.bumps.holding;
+    holding.version = Holding::CURRENT_VERSION;
+
+    let player_state = &mut ctx.accounts.player_state;
+    player_state.holdings_count = player_state.holdings_count.saturating_add(1);
+
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.buyer.key(),
+            action: SpendAction::BuyAsset,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     emit!(AssetBought {
         player: holding.player,
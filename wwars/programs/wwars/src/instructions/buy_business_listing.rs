@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::{BusinessListing, GameConfig, PlayerState, WorkConfig, PAUSE_BUSINESS};
+use crate::math::bps_of;
+use crate::errors::ErrorCode;
+use crate::events::BusinessTraded;
+use crate::instructions::purchase_business::add_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+
+#[derive(Accounts)]
+#[instruction(seller: Pubkey, business_id: u8)]
+pub struct BuyBusinessListing<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", buyer.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"listing".as_ref(), seller.as_ref(), &[business_id]],
+        bump = listing.bump,
+        close = seller_wallet
+    )]
+    pub listing: Account<'info, BusinessListing>,
+    
+    /// CHECK: Receives the listing rent; must be the seller
+    #[account(mut, address = listing.seller @ ErrorCode::Unauthorized)]
+    pub seller_wallet: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = listing.seller
+    )]
+    pub seller_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = buyer
+    )]
+    pub buyer_token_account: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = game_config,
+        seeds = [b"vault", game_config.key().as_ref()],
+        bump = game_config.bump_vault
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// `remaining_accounts` must hold the `BusinessType` account of every business
+/// the buyer owns, in ownership order, so pending income is settled first.
+pub fn buy_business_listing<'info>(
+    ctx: Context<'_, '_, 'info, 'info, BuyBusinessListing<'info>>,
+    _seller: Pubkey,
+    business_id: u8,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let listing = &ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    require!(!player.businesses_owned.contains(&business_id), ErrorCode::AlreadyOwned);
+    
+    // Marketplace fee goes to the treasury vault, the rest to the seller
+    let fee = bps_of(listing.price, ctx.accounts.game_config.fee_bps)?;
+    let seller_amount = listing.price.checked_sub(fee).ok_or(ErrorCode::MathOverflow)?;
+    
+    if fee > 0 {
+        let transfer_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.buyer_token_account.to_account_info(),
+                to: ctx.accounts.treasury_vault.to_account_info(),
+                authority: ctx.accounts.buyer.to_account_info(),
+            },
+        );
+        token::transfer(transfer_ctx, fee)?;
+    }
+    
+    let transfer_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Transfer {
+            from: ctx.accounts.buyer_token_account.to_account_info(),
+            to: ctx.accounts.seller_token_account.to_account_info(),
+            authority: ctx.accounts.buyer.to_account_info(),
+        },
+    );
+    token::transfer(transfer_ctx, seller_amount)?;
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    add_business(player, &ctx.accounts.work_config, business_id, listing.level);
+    
+    emit!(BusinessTraded {
+        seller: listing.seller,
+        buyer: player.owner,
+        business_id,
+        level: listing.level,
+        price: listing.price,
+        fee,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::math::*;
+use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct BuyStreakFreeze<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_WORK) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+}
+
+#[derive(Accounts)]
+pub struct BuyStreakFreezeWithWealth<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_WORK) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = game_config,
+        seeds = [b"vault", game_config.key().as_ref()],
+        bump = game_config.bump_vault
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn buy_streak_freeze(ctx: Context<BuyStreakFreeze>, quantity: u8) -> Result<()> {
+    let work_config = &ctx.accounts.work_config;
+    let player_state = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+
+    let freezes_held = add_streak_freezes(player_state, work_config, quantity)?;
+
+    let price = work_config.streak_freeze_price_credits;
+    require!(price > 0, ErrorCode::StreakFreezeNotForSale);
+    let cost = price.checked_mul(quantity as u64).ok_or(ErrorCode::MathOverflow)?;
+    require!(player_state.credits >= cost, ErrorCode::InsufficientCredits);
+    player_state.credits -= cost;
+
+    emit!(StreakFreezesPurchased {
+        player: player_state.owner,
+        quantity,
+        credits_paid: cost,
+        wealth_paid: 0,
+        freezes_held,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+pub fn buy_streak_freeze_with_wealth(ctx: Context<BuyStreakFreezeWithWealth>, quantity: u8) -> Result<()> {
+    let work_config = &ctx.accounts.work_config;
+    let game_config = &ctx.accounts.game_config;
+    let player_state = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+
+    let freezes_held = add_streak_freezes(player_state, work_config, quantity)?;
+
+    let price = work_config.streak_freeze_price_wealth;
+    require!(price > 0, ErrorCode::StreakFreezeNotForSale);
+    let cost = price.checked_mul(quantity as u64).ok_or(ErrorCode::MathOverflow)?;
+
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(cost, game_config.burn_bps)?;
+    let vault_amount = cost.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.owner_token_account.as_ref(),
+        ctx.accounts.owner.to_account_info(),
+        &[],
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        player_state,
+        cost,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
+
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.owner.key(),
+            action: SpendAction::StreakFreeze,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(StreakFreezesPurchased {
+        player: ctx.accounts.player_state.owner,
+        quantity,
+        credits_paid: 0,
+        wealth_paid: cost,
+        freezes_held,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Adds `quantity` freezes within the configured cap. Returns the new count.
+fn add_streak_freezes(player_state: &mut PlayerState, work_config: &WorkConfig, quantity: u8) -> Result<u8> {
+    require!(quantity > 0, ErrorCode::ZeroAmount);
+
+    let held = player_state
+        .streak_freezes
+        .checked_add(quantity)
+        .ok_or(ErrorCode::StreakFreezeLimit)?;
+    require!(held <= work_config.max_streak_freezes, ErrorCode::StreakFreezeLimit);
+
+    player_state.streak_freezes = held;
+    Ok(held)
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::{BusinessListing, GameConfig, PlayerState, WorkConfig};
+use crate::errors::ErrorCode;
+use crate::events::BusinessListingCancelled;
+use crate::instructions::purchase_business::add_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct CancelBusinessListing<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", seller.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"listing".as_ref(), seller.key().as_ref(), &[business_id]],
+        bump = listing.bump,
+        has_one = seller @ ErrorCode::Unauthorized,
+        close = seller
+    )]
+    pub listing: Account<'info, BusinessListing>,
+}
+
+/// Returns an escrowed business to the seller. `remaining_accounts` must hold
+/// the `BusinessType` account of every owned business, in ownership order.
+pub fn cancel_business_listing<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CancelBusinessListing<'info>>,
+    business_id: u8,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let listing = &ctx.accounts.listing;
+    let clock = Clock::get()?;
+    
+    // The seller may have bought the same business again meanwhile
+    require!(!player.businesses_owned.contains(&business_id), ErrorCode::AlreadyOwned);
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    add_business(player, &ctx.accounts.work_config, business_id, listing.level);
+    
+    emit!(BusinessListingCancelled {
+        seller: listing.seller,
+        business_id,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::{BusinessType, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::errors::ErrorCode;
+use crate::events::BusinessIncomeClaimed;
+use crate::instructions::purchase_business::load_business_types;
+
+// Longest offline period that keeps accruing income (24h)
+pub const MAX_INCOME_ACCRUAL_SECS: i64 = 24 * 3600;
+
+#[derive(Accounts)]
+pub struct ClaimBusinessIncome<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
+
+/// `remaining_accounts` must hold the `BusinessType` account of every owned
+/// business, in ownership order.
+pub fn claim_business_income<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimBusinessIncome<'info>>,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    let business_types = load_business_types(&player.businesses_owned, ctx.remaining_accounts)?;
+    let window_opened = player.last_income_claim_ts == 0;
+    let (amount, accrued_secs) = settle_business_income(player, &business_types, clock.unix_timestamp)?;
+    
+    // Migrated accounts only open their income window on the first settle;
+    // keep it rather than reverting with nothing to pay
+    if window_opened {
+        return Ok(());
+    }
+    require!(amount > 0, ErrorCode::ZeroAmount);
+    
+    emit!(BusinessIncomeClaimed {
+        player: player.owner,
+        amount,
+        accrued_secs,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Settles income on every owned business before ownership changes, emitting
+/// `BusinessIncomeClaimed` when anything was paid. `business_accounts` must
+/// hold the `BusinessType` of every owned business, in ownership order.
+pub fn settle_and_emit_income<'info>(
+    player: &mut PlayerState,
+    business_accounts: &'info [AccountInfo<'info>],
+    now: i64,
+) -> Result<()> {
+    let owned_types = load_business_types(&player.businesses_owned, business_accounts)?;
+    let (income, accrued_secs) = settle_business_income(player, &owned_types, now)?;
+    if income > 0 {
+        emit!(BusinessIncomeClaimed {
+            player: player.owner,
+            amount: income,
+            accrued_secs,
+            timestamp: now,
+        });
+    }
+    Ok(())
+}
+
+/// Credits the income accrued since `last_income_claim_ts`, capped at
+/// `MAX_INCOME_ACCRUAL_SECS`, and restarts the window. Returns the amount
+/// paid and the seconds it covered.
+pub fn settle_business_income(
+    player: &mut PlayerState,
+    business_types: &[BusinessType],
+    now: i64,
+) -> Result<(u64, i64)> {
+    // Accounts migrated from before passive income start accruing now
+    let accrued_secs = if player.last_income_claim_ts == 0 {
+        0
+    } else {
+        now.saturating_sub(player.last_income_claim_ts).clamp(0, MAX_INCOME_ACCRUAL_SECS)
+    };
+    
+    let rate_per_hour = business_types
+        .iter()
+        .try_fold(0u64, |acc, b| acc.checked_add(b.passive_income_per_hour))
+        .ok_or(ErrorCode::MathOverflow)?;
+    let income: u64 = (rate_per_hour as u128 * accrued_secs as u128 / 3600)
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    
+    player.credits = player.credits.checked_add(income).ok_or(ErrorCode::MathOverflow)?;
+    player.last_income_claim_ts = now;
+    
+    Ok((income, accrued_secs))
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::{BusinessNft, BusinessType, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::errors::ErrorCode;
+use crate::events::BusinessNftIncomeClaimed;
+use crate::instructions::claim_business_income::MAX_INCOME_ACCRUAL_SECS;
+
+#[derive(Accounts)]
+pub struct ClaimBusinessNftIncome<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", holder.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub holder: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"business_nft", business_nft.mint.as_ref()],
+        bump = business_nft.bump
+    )]
+    pub business_nft: Account<'info, BusinessNft>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_nft.business_id]],
+        bump = business_type.bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+    
+    // Ownership is whoever holds the token, wherever it was traded
+    #[account(
+        constraint = holder_nft_account.mint == business_nft.mint @ ErrorCode::NotOwned,
+        constraint = holder_nft_account.owner == holder.key() @ ErrorCode::NotOwned,
+        constraint = holder_nft_account.amount == 1 @ ErrorCode::NotOwned
+    )]
+    pub holder_nft_account: InterfaceAccount<'info, TokenAccount>,
+}
+
+/// Pays the income a wrapped business accrued to whoever holds its NFT.
+pub fn claim_business_nft_income(ctx: Context<ClaimBusinessNftIncome>) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let record = &mut ctx.accounts.business_nft;
+    let clock = Clock::get()?;
+    
+    let window_opened = record.last_income_claim_ts == 0;
+    let (amount, accrued_secs) =
+        settle_business_nft_income(player, record, &ctx.accounts.business_type, clock.unix_timestamp)?;
+    
+    // Records migrated from before NFT income only open their window here
+    if window_opened {
+        return Ok(());
+    }
+    require!(amount > 0, ErrorCode::ZeroAmount);
+    
+    emit!(BusinessNftIncomeClaimed {
+        player: player.owner,
+        mint: record.mint,
+        business_id: record.business_id,
+        amount,
+        accrued_secs,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Credits `player` with the income `record` accrued since its
+/// `last_income_claim_ts`, capped at `MAX_INCOME_ACCRUAL_SECS`, and restarts
+/// the window. Returns the amount paid and the seconds it covered.
+pub fn settle_business_nft_income(
+    player: &mut PlayerState,
+    record: &mut BusinessNft,
+    business_type: &BusinessType,
+    now: i64,
+) -> Result<(u64, i64)> {
+    let accrued_secs = if record.last_income_claim_ts == 0 {
+        0
+    } else {
+        now.saturating_sub(record.last_income_claim_ts).clamp(0, MAX_INCOME_ACCRUAL_SECS)
+    };
+    
+    let income: u64 = (business_type.passive_income_per_hour as u128 * accrued_secs as u128 / 3600)
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    
+    player.credits = player.credits.checked_add(income).ok_or(ErrorCode::MathOverflow)?;
+    record.last_income_claim_ts = now;
+    
+    Ok((income, accrued_secs))
+}
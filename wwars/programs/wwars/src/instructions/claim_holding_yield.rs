@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::math::*;
+use crate::events::*;
+use crate::errors::ErrorCode;
+
+// Unclaimed yield stops accruing after a day
+pub const MAX_YIELD_ACCRUAL_SECS: i64 = 24 * 3600;
+
+#[derive(Accounts)]
+#[instruction(class_id: u64)]
+pub struct ClaimHoldingYield<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        constraint = !game_config.is_paused(PAUSE_HOLDING_YIELD) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"class", &class_id.to_le_bytes()],
+        bump = asset_class.bump
+    )]
+    pub asset_class: Account<'info, AssetClass>,
+
+    #[account(
+        mut,
+        constraint = holding.class == asset_class.key() @ ErrorCode::InvalidParameters
+    )]
+    pub holding: Account<'info, Holding>,
+
+    /// Token account holding the holding's NFT, required once it is tokenized
+    pub holder_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+}
+
+/// Pays `base_yield` credits per level per day since the last claim.
+pub fn claim_holding_yield(ctx: Context<ClaimHoldingYield>, _class_id: u64) -> Result<()> {
+    let holding = &mut ctx.accounts.holding;
+    let asset_class = &ctx.accounts.asset_class;
+    let player_state = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+
+    authorize_holding(holding, &ctx.accounts.owner.key(), ctx.accounts.holder_nft_account.as_deref())?;
+
+    let accrued_secs = clock
+        .unix_timestamp
+        .saturating_sub(holding.last_claim_ts)
+        .clamp(0, MAX_YIELD_ACCRUAL_SECS);
+    let amount: u64 = (asset_class.base_yield as u128)
+        .checked_mul(holding.level as u128)
+        .and_then(|x| x.checked_mul(accrued_secs as u128))
+        .and_then(|x| x.checked_div(24 * 3600))
+        .and_then(|x| x.try_into().ok())
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    player_state.credits = player_state.credits.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+    holding.last_claim_ts = clock.unix_timestamp;
+
+    emit!(HoldingYieldClaimed {
+        player: holding.player,
+        class: holding.class,
+        level: holding.level,
+        amount,
+        accrued_secs,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::events::PassRewardClaimed;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct ClaimPassReward<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"season_pass_config"],
+        bump = pass_config.bump
+    )]
+    pub pass_config: Account<'info, SeasonPassConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &season_id.to_le_bytes(), owner.key().as_ref()],
+        bump = season_pass.bump
+    )]
+    pub season_pass: Account<'info, SeasonPass>,
+
+    #[account(
+        mut,
+        seeds = [b"pass_reward_vault", game_config.key().as_ref()],
+        bump = pass_config.bump_reward_vault
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Pays the unclaimed free reward of `level`, plus the premium one when the
+/// pass is premium. Works for past seasons too.
+pub fn claim_pass_reward(ctx: Context<ClaimPassReward>, _season_id: u32, level: u8) -> Result<()> {
+    let pass_config = &ctx.accounts.pass_config;
+    let pass = &mut ctx.accounts.season_pass;
+
+    require!(level >= 1 && level <= pass.level(), ErrorCode::PassLevelNotReached);
+
+    let claim_free = !SeasonPass::is_claimed(&pass.claimed_free, level);
+    let claim_premium = pass.premium && !SeasonPass::is_claimed(&pass.claimed_premium, level);
+    require!(claim_free || claim_premium, ErrorCode::PassRewardClaimed);
+
+    let mut free_amount = 0;
+    if claim_free {
+        SeasonPass::set_claimed(&mut pass.claimed_free, level);
+        free_amount = pass_config.free_reward;
+    }
+    let mut premium_amount = 0;
+    if claim_premium {
+        SeasonPass::set_claimed(&mut pass.claimed_premium, level);
+        premium_amount = pass_config.premium_reward;
+    }
+    let amount = free_amount.checked_add(premium_amount).ok_or(ErrorCode::MathOverflow)?;
+    require!(ctx.accounts.reward_vault.amount >= amount, ErrorCode::InsufficientFunds);
+
+    if amount > 0 {
+        let config_seeds = &[b"config".as_ref(), &[ctx.accounts.game_config.bump_config]];
+        let signer = &[&config_seeds[..]];
+
+        let cpi_accounts = Transfer {
+            from: ctx.accounts.reward_vault.to_account_info(),
+            to: ctx.accounts.owner_token_account.to_account_info(),
+            authority: ctx.accounts.game_config.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+    }
+
+    emit!(PassRewardClaimed {
+        player: pass.player,
+        season_id: pass.season_id,
+        level,
+        free_amount,
+        premium_amount,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::SeasonCreated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(id: u32)]
+pub struct CreateSeason<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized,
+        constraint = id > game_config.current_season @ ErrorCode::InvalidSeason
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Season::SPACE,
+        seeds = [b"season".as_ref(), &id.to_le_bytes()],
+        bump
+    )]
+    pub season: Account<'info, Season>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    id: u32,
+    start_ts: i64,
+    end_ts: i64,
+    rules: CarryOverRules,
+) -> Result<()> {
+    require!(start_ts < end_ts, ErrorCode::InvalidSeason);
+    require!(rules.credits_keep_bps <= 10000, ErrorCode::InvalidParameters);
+
+    let season = &mut ctx.accounts.season;
+
+    season.id = id;
+    season.start_ts = start_ts;
+    season.end_ts = end_ts;
+    season.rules = rules;
+    season.players_rolled = 0;
+    season.bump = ctx.bumps.season;
+    season.version = Season::CURRENT_VERSION;
+
+    emit!(SeasonCreated {
+        id,
+        start_ts,
+        end_ts,
+        rules: season.rules.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Approve, Token, TokenAccount};
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::SessionCreated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct CreateSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: Any key the owner chooses to delegate to
+    pub session_signer: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SessionKey::SPACE,
+        seeds = [b"session", owner.key().as_ref(), session_signer.key().as_ref()],
+        bump
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    /// WEALTH account the session may spend from; required for a WEALTH limit
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+
+    /// SPL credits account the session may spend from; approved for the
+    /// credits limit when passed
+    #[account(
+        mut,
+        token::authority = owner
+    )]
+    pub owner_credits_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateSessionParams {
+    pub duration_secs: i64,
+    pub allowed_actions: u16,
+    pub max_credits_spend: u64,
+    pub max_wealth_spend: u64,
+}
+
+/// Opens a session for `session_signer`. A WEALTH limit approves the session
+/// PDA as delegate of the owner's WEALTH account, and a credits limit does the
+/// same for the owner's SPL credits account when it is passed. Fails if either
+/// account already has a delegate, so an open session is never displaced.
+pub fn create_session(ctx: Context<CreateSession>, params: CreateSessionParams) -> Result<()> {
+    require!(
+        params.duration_secs > 0 && params.duration_secs <= MAX_SESSION_DURATION,
+        ErrorCode::InvalidParameters
+    );
+    require!(
+        params.allowed_actions != 0 && params.allowed_actions & !SESSION_ACTIONS_MASK == 0,
+        ErrorCode::InvalidParameters
+    );
+    require_keys_neq!(ctx.accounts.session_signer.key(), ctx.accounts.owner.key(), ErrorCode::InvalidParameters);
+
+    let clock = Clock::get()?;
+    let session = &mut ctx.accounts.session;
+
+    session.owner = ctx.accounts.owner.key();
+    session.session_signer = ctx.accounts.session_signer.key();
+    session.expires_at = clock.unix_timestamp.checked_add(params.duration_secs).ok_or(ErrorCode::MathOverflow)?;
+    session.allowed_actions = params.allowed_actions;
+    session.max_credits_spend = params.max_credits_spend;
+    session.credits_spent = 0;
+    session.max_wealth_spend = params.max_wealth_spend;
+    session.wealth_spent = 0;
+    session.created_ts = clock.unix_timestamp;
+    session.bump = ctx.bumps.session;
+    session.version = SessionKey::CURRENT_VERSION;
+
+    if params.max_wealth_spend > 0 {
+        let owner_token_account = ctx.accounts.owner_token_account.as_ref().ok_or(ErrorCode::InvalidParameters)?;
+        require!(owner_token_account.delegate.is_none(), ErrorCode::TokenDelegateInUse);
+        let cpi_accounts = Approve {
+            to: owner_token_account.to_account_info(),
+            delegate: session.to_account_info(),
+            authority: ctx.accounts.owner.to_account_info(),
+        };
+        let cpi_program = ctx.accounts.token_program.to_account_info();
+        let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+        token::approve(cpi_ctx, params.max_wealth_spend)?;
+    }
+
+    if params.max_credits_spend > 0 {
+        if let Some(owner_credits_account) = &ctx.accounts.owner_credits_account {
+            let credits_mint_config = ctx.accounts.credits_mint_config.as_ref().ok_or(ErrorCode::CreditsMintAccountsMissing)?;
+            require!(credits_mint_config.enabled, ErrorCode::CreditsMintDisabled);
+            require_keys_eq!(owner_credits_account.mint, credits_mint_config.mint, ErrorCode::InvalidParameters);
+            require!(owner_credits_account.delegate.is_none(), ErrorCode::TokenDelegateInUse);
+            let cpi_accounts = Approve {
+                to: owner_credits_account.to_account_info(),
+                delegate: session.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::approve(cpi_ctx, params.max_credits_spend)?;
+        }
+    }
+
+    emit!(SessionCreated {
+        owner: session.owner,
+        session_signer: session.session_signer,
+        expires_at: session.expires_at,
+        allowed_actions: session.allowed_actions,
+        max_credits_spend: session.max_credits_spend,
+        max_wealth_spend: session.max_wealth_spend,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
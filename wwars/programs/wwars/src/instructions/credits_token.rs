@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer};
+use crate::state::{CreditsMintConfig, PlayerState};
+use crate::errors::ErrorCode;
+
+/// The player's SPL credits, used in place of `PlayerState::credits` when the
+/// caller passes them and the credits mint is enabled. Credits paid out here
+/// count toward `PlayerState::bridged_credits` and spends come out of it, so
+/// only tokens the program gave the player can be spent in the game.
+pub struct SplCredits<'a, 'info> {
+    pub config: &'a Account<'info, CreditsMintConfig>,
+    pub mint: &'a Account<'info, Mint>,
+    pub account: &'a Account<'info, TokenAccount>,
+    pub token_program: &'a Program<'info, Token>,
+}
+
+impl<'a, 'info> SplCredits<'a, 'info> {
+    /// Returns `None` when the player's credits token account is not passed,
+    /// so the ledger is used. Passing it without the other accounts is an
+    /// error.
+    pub fn resolve(
+        config: Option<&'a Account<'info, CreditsMintConfig>>,
+        mint: Option<&'a Account<'info, Mint>>,
+        account: Option<&'a Account<'info, TokenAccount>>,
+        token_program: Option<&'a Program<'info, Token>>,
+        owner: &Pubkey,
+    ) -> Result<Option<Self>> {
+        let Some(account) = account else {
+            return Ok(None);
+        };
+        let (Some(config), Some(mint), Some(token_program)) = (config, mint, token_program) else {
+            return err!(ErrorCode::CreditsMintAccountsMissing);
+        };
+
+        require!(config.enabled, ErrorCode::CreditsMintDisabled);
+        require_keys_eq!(mint.key(), config.mint, ErrorCode::InvalidParameters);
+        require_keys_eq!(account.mint, config.mint, ErrorCode::InvalidParameters);
+        require_keys_eq!(account.owner, *owner, ErrorCode::Unauthorized);
+        Ok(Some(SplCredits { config, mint, account, token_program }))
+    }
+
+    /// Mints `amount` new credits into the player's token account.
+    pub fn mint_to_player(&self, player: &mut PlayerState, amount: u64) -> Result<()> {
+        player.bridged_credits = player.bridged_credits.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        let config_seeds = &[b"credits_mint_config".as_ref(), &[self.config.bump]];
+        let config_signer = &[&config_seeds[..]];
+        let cpi_accounts = MintTo {
+            mint: self.mint.to_account_info(),
+            to: self.account.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, config_signer);
+        token::mint_to(cpi_ctx, amount)
+    }
+
+    /// Burns `amount` credits from the player's token account.
+    pub fn burn_from_player(
+        &self,
+        player: &mut PlayerState,
+        authority: AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+        amount: u64,
+    ) -> Result<()> {
+        require!(self.account.amount >= amount, ErrorCode::InsufficientCredits);
+        player.take_bridged_credits(amount)?;
+        let cpi_accounts = Burn {
+            mint: self.mint.to_account_info(),
+            from: self.account.to_account_info(),
+            authority,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+        token::burn(cpi_ctx, amount)
+    }
+
+    /// Moves `amount` credits from the player's token account to `to`.
+    pub fn transfer_from_player(
+        &self,
+        player: &mut PlayerState,
+        to: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        signer: &[&[&[u8]]],
+        amount: u64,
+    ) -> Result<()> {
+        require!(self.account.amount >= amount, ErrorCode::InsufficientCredits);
+        player.take_bridged_credits(amount)?;
+        let cpi_accounts = Transfer {
+            from: self.account.to_account_info(),
+            to,
+            authority,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(self.token_program.to_account_info(), cpi_accounts, signer);
+        token::transfer(cpi_ctx, amount)
+    }
+}
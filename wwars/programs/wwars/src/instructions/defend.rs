@@ -1,26 +1,35 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface;
 use crate::state::*;
 use crate::math::*;
 use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
+use crate::instructions::open_achievements::unlock_achievement;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 #[instruction(class_id: u64)]
 pub struct Defend<'info> {
+    /// CHECK: Player wallet; signs itself or through `session`
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub owner: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub signer: Signer<'info>,
 
     #[account(
         mut,
-        seeds = [b"player", owner.key().as_ref()],
-        bump = player.bump,
-        constraint = player.authority == owner.key() @ ErrorCode::Unauthorized
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
-    pub player: Account<'info, Player>,
+    pub player_state: Account<'info, PlayerState>,
 
     #[account(
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        mut,
+        constraint = !game_config.is_paused(PAUSE_DEFEND) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -32,18 +41,27 @@ pub struct Defend<'info> {
 
     #[account(
         mut,
-        seeds = [b"holding", owner.key().as_ref(), asset_class.key().as_ref()],
-        bump = holding.bump,
-        has_one = player @ ErrorCode::Unauthorized
+        constraint = holding.class == asset_class.key() @ ErrorCode::InvalidParameters
     )]
     pub holding: Account<'info, Holding>,
 
+    /// Token account holding the holding's NFT, required once it is tokenized
+    pub holder_nft_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
     #[account(
         mut,
         token::mint = game_config.wealth_mint,
         token::authority = owner
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -54,33 +72,104 @@ pub struct Defend<'info> {
     )]
     pub treasury_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
+
+    #[account(
+        mut,
+        seeds = [b"session", owner.key().as_ref(), signer.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+
+    /// Current season pass; earns XP while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &season_pass.season_id.to_le_bytes(), owner.key().as_ref()],
+        bump = season_pass.bump
+    )]
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+
+    /// Achievements to unlock milestones into
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
 }
 
 pub fn defend(ctx: Context<Defend>, _class_id: u64, spend_amount: u64) -> Result<()> {
+    require!(spend_amount > 0, ErrorCode::ZeroAmount);
+    let owner_key = ctx.accounts.owner.key();
+    let signer_key = ctx.accounts.signer.key();
+    let via_session = authorize_player(
+        &owner_key,
+        &signer_key,
+        ctx.accounts.session.as_deref_mut(),
+        SESSION_DEFEND,
+        0,
+        spend_amount,
+        Clock::get()?.unix_timestamp,
+    )?;
+
+    // Session spends go through the session PDA as token delegate
+    let (spend_authority, session_bump) = match &ctx.accounts.session {
+        Some(session) if via_session => (session.to_account_info(), session.bump),
+        _ => (ctx.accounts.owner.to_account_info(), 0),
+    };
+    let session_seeds = &[b"session".as_ref(), owner_key.as_ref(), signer_key.as_ref(), &[session_bump]];
+    let session_signer = &[&session_seeds[..]];
+    let spend_signer: &[&[&[u8]]] = if via_session { session_signer } else { &[] };
+
     let holding = &mut ctx.accounts.holding;
     let asset_class = &ctx.accounts.asset_class;
     let game_config = &ctx.accounts.game_config;
-    let player = &mut ctx.accounts.player;
+    let player_state = &mut ctx.accounts.player_state;
     let clock = Clock::get()?;
 
+    authorize_holding(holding, &ctx.accounts.owner.key(), ctx.accounts.holder_nft_account.as_deref())?;
+
     // Check defend cooldown
     let defend_cd = defend_cd(asset_class, game_config);
-    let time_since_last_defend = clock.unix_timestamp.saturating_sub(player.last_defend_ts);
+    let time_since_last_defend = clock.unix_timestamp.saturating_sub(player_state.last_defend_ts);
     require!(time_since_last_defend >= defend_cd, ErrorCode::CooldownNotExpired);
 
     // Update risk first
     update_risk(holding, asset_class, game_config, clock.unix_timestamp);
-
-    // Transfer tokens
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        to: ctx.accounts.treasury_vault.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, spend_amount)?;
+    let was_at_risk = holding.risk_score >= game_config.risk_threshold;
+
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(spend_amount, game_config.burn_bps)?;
+    let vault_amount = spend_amount.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.owner_token_account.as_ref(),
+        spend_authority,
+        spend_signer,
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        player_state,
+        spend_amount,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
+
+    if let Some(pass) = ctx.accounts.season_pass.as_deref_mut() {
+        pass.add_xp(XP_DEFEND, clock.unix_timestamp);
+    }
 
     // Reduce risk
     let risk_reduction = (spend_amount as u128)
@@ -94,7 +183,26 @@ pub fn defend(ctx: Context<Defend>, _class_id: u64, spend_amount: u64) -> Result
 
     // Update timestamps
     holding.last_defend_ts = clock.unix_timestamp;
-    player.last_defend_ts = clock.unix_timestamp;
+    player_state.last_defend_ts = clock.unix_timestamp;
+
+    if was_at_risk && holding.risk_score < game_config.risk_threshold {
+        if let Some(achievements) = ctx.accounts.achievements.as_deref_mut() {
+            unlock_achievement(achievements, player_state, ACH_SUCCESSFUL_DEFENSE, clock.unix_timestamp)?;
+        }
+    }
+
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.owner.key(),
+            action: SpendAction::Defend,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
 
     emit!(Defended {
         player: holding.player,
@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::events::CreditsDeposited;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DepositCredits<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_CREDITS_BRIDGE) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump,
+        constraint = credits_mint_config.enabled @ ErrorCode::CreditsMintDisabled
+    )]
+    pub credits_mint_config: Account<'info, CreditsMintConfig>,
+
+    #[account(
+        mut,
+        address = credits_mint_config.mint
+    )]
+    pub credits_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = credits_mint,
+        token::authority = owner
+    )]
+    pub owner_credits_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Burns SPL credits from the owner's token account and adds them to
+/// `PlayerState::credits`, up to the SPL credits the program paid out to
+/// this player. Tokens bought or received from other wallets stay outside
+/// the ledger; `transfer_credits` is the way to move credits between players.
+pub fn deposit_credits(ctx: Context<DepositCredits>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::ZeroAmount);
+    require!(ctx.accounts.owner_credits_account.amount >= amount, ErrorCode::InsufficientFunds);
+    ctx.accounts.player_state.take_bridged_credits(amount)?;
+
+    let cpi_accounts = Burn {
+        mint: ctx.accounts.credits_mint.to_account_info(),
+        from: ctx.accounts.owner_credits_account.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::burn(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    let player = &mut ctx.accounts.player_state;
+    player.credits = player.credits.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    let config = &mut ctx.accounts.credits_mint_config;
+    config.total_deposited = config.total_deposited.saturating_add(amount);
+
+    emit!(CreditsDeposited {
+        player: player.owner,
+        amount,
+        credits: player.credits,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::math::bps_of;
+use crate::events::RevenueDistributed;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct DistributeRevenue<'info> {
+    /// Anyone can crank the router
+    pub caller: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"revenue", game_config.key().as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = game_config,
+        seeds = [b"vault", game_config.key().as_ref()],
+        bump = game_config.bump_vault
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        address = revenue_config.rewards_vault
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = revenue_config.treasury_destination
+    )]
+    pub treasury_destination: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn distribute_revenue(ctx: Context<DistributeRevenue>) -> Result<()> {
+    let game_config = &ctx.accounts.game_config;
+    let revenue = &ctx.accounts.revenue_config;
+    let clock = Clock::get()?;
+
+    let total = ctx.accounts.treasury_vault.amount;
+    require!(total > 0, ErrorCode::ZeroAmount);
+
+    // Treasury takes the rounding remainder so the vault is fully drained
+    let to_rewards = bps_of(total, revenue.rewards_bps)?;
+    let burned = bps_of(total, revenue.burn_bps)?;
+    let to_treasury = total
+        .checked_sub(to_rewards)
+        .and_then(|x| x.checked_sub(burned))
+        .ok_or(ErrorCode::MathOverflow)?;
+
+    let config_seeds = &[b"config".as_ref(), &[game_config.bump_config]];
+    let signer_seeds = &[&config_seeds[..]];
+
+    if to_rewards > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                to: ctx.accounts.rewards_vault.to_account_info(),
+                authority: ctx.accounts.game_config.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, to_rewards)?;
+    }
+
+    if to_treasury > 0 {
+        let transfer_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                to: ctx.accounts.treasury_destination.to_account_info(),
+                authority: ctx.accounts.game_config.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::transfer(transfer_ctx, to_treasury)?;
+    }
+
+    if burned > 0 {
+        let burn_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.wealth_mint.to_account_info(),
+                from: ctx.accounts.treasury_vault.to_account_info(),
+                authority: ctx.accounts.game_config.to_account_info(),
+            },
+            signer_seeds,
+        );
+        token::burn(burn_ctx, burned)?;
+    }
+
+    let revenue = &mut ctx.accounts.revenue_config;
+    revenue.total_distributed = revenue.total_distributed.saturating_add(total);
+    revenue.total_burned = revenue.total_burned.saturating_add(burned);
+    revenue.last_distribution_ts = clock.unix_timestamp;
+
+    let game_config = &mut ctx.accounts.game_config;
+    game_config.total_burned = game_config.total_burned.saturating_add(burned);
+
+    emit!(RevenueDistributed {
+        caller: ctx.accounts.caller.key(),
+        total,
+        to_rewards,
+        to_treasury,
+        burned,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 use crate::state::*;
+use crate::math::authorize_holding;
 use crate::events::*;
+use crate::instructions::open_achievements::unlock_holding_level_achievement;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -11,14 +14,15 @@ pub struct FinishUpgrade<'info> {
 
     #[account(
         mut,
-        seeds = [b"player", owner.key().as_ref()],
-        bump = player.bump,
-        constraint = player.authority == owner.key() @ ErrorCode::Unauthorized
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
-    pub player: Account<'info, Player>,
+    pub player_state: Account<'info, PlayerState>,
 
     #[account(
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        constraint = !game_config.is_paused(PAUSE_UPGRADE) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -30,18 +34,29 @@ pub struct FinishUpgrade<'info> {
 
     #[account(
         mut,
-        seeds = [b"holding", owner.key().as_ref(), asset_class.key().as_ref()],
-        bump = holding.bump,
-        has_one = player @ ErrorCode::Unauthorized,
+        constraint = holding.class == asset_class.key() @ ErrorCode::InvalidParameters,
         constraint = holding.upgrade_end_ts > 0 @ ErrorCode::InvalidParameters
     )]
     pub holding: Account<'info, Holding>,
+
+    /// Token account holding the holding's NFT, required once it is tokenized
+    pub holder_nft_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// Achievements to unlock milestones into
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
 }
 
 pub fn finish_upgrade(ctx: Context<FinishUpgrade>, _class_id: u64) -> Result<()> {
     let holding = &mut ctx.accounts.holding;
     let clock = Clock::get()?;
 
+    authorize_holding(holding, &ctx.accounts.owner.key(), ctx.accounts.holder_nft_account.as_deref())?;
+
     // Check if upgrade is ready
     require!(clock.unix_timestamp >= holding.upgrade_end_ts, ErrorCode::InvalidParameters);
 
@@ -49,6 +64,10 @@ pub fn finish_upgrade(ctx: Context<FinishUpgrade>, _class_id: u64) -> Result<()>
     holding.level = holding.level.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
     holding.upgrade_end_ts = 0;
 
+    if let Some(achievements) = ctx.accounts.achievements.as_deref_mut() {
+        unlock_holding_level_achievement(achievements, &mut ctx.accounts.player_state, holding, clock.unix_timestamp)?;
+    }
+
     emit!(UpgradeFinished {
         player: holding.player,
         class: holding.class,
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::metadata::{
+    self, mpl_token_metadata::types::DataV2, CreateMetadataAccountsV3, Metadata,
+};
+use anchor_spl::token_2022::{spl_token_2022::instruction::AuthorityType, Token2022};
+use anchor_spl::token_interface::{self, Mint, MintTo, SetAuthority, TokenAccount};
+use crate::state::GameConfig;
+
+// Every game NFT is a supply-1 Token-2022 mint with a Metaplex Token Metadata
+// account, so wallets and marketplaces pick it up. The game config PDA is the
+// metadata update authority.
+
+/// Creates the Metaplex metadata for a freshly created game NFT mint, mints
+/// the single token to `destination` and revokes the mint authority.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_game_nft<'info>(
+    game_config: &Account<'info, GameConfig>,
+    mint: &InterfaceAccount<'info, Mint>,
+    metadata: &UncheckedAccount<'info>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    payer: &Signer<'info>,
+    token_program: &Program<'info, Token2022>,
+    token_metadata_program: &Program<'info, Metadata>,
+    system_program: &Program<'info, System>,
+    name: String,
+    symbol: String,
+    uri: String,
+) -> Result<()> {
+    let mint_info = mint.to_account_info();
+    let config_seeds = &[b"config".as_ref(), &[game_config.bump_config]];
+    let signer = &[&config_seeds[..]];
+
+    let metadata_ctx = CpiContext::new_with_signer(
+        token_metadata_program.to_account_info(),
+        CreateMetadataAccountsV3 {
+            metadata: metadata.to_account_info(),
+            mint: mint_info.clone(),
+            mint_authority: game_config.to_account_info(),
+            payer: payer.to_account_info(),
+            update_authority: game_config.to_account_info(),
+            system_program: system_program.to_account_info(),
+            // The rent sysvar is optional for this instruction and not read
+            rent: system_program.to_account_info(),
+        },
+        signer,
+    );
+    let data = DataV2 {
+        name,
+        symbol,
+        uri,
+        seller_fee_basis_points: 0,
+        creators: None,
+        collection: None,
+        uses: None,
+    };
+    metadata::create_metadata_accounts_v3(metadata_ctx, data, true, true, None)?;
+
+    let mint_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        MintTo {
+            mint: mint_info.clone(),
+            to: destination.to_account_info(),
+            authority: game_config.to_account_info(),
+        },
+        signer,
+    );
+    token_interface::mint_to(mint_ctx, 1)?;
+
+    // Drop the mint authority so the supply stays at one
+    let authority_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        SetAuthority {
+            current_authority: game_config.to_account_info(),
+            account_or_mint: mint_info,
+        },
+        signer,
+    );
+    token_interface::set_authority(authority_ctx, AuthorityType::MintTokens, None)?;
+
+    Ok(())
+}
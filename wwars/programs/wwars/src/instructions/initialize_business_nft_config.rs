@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::BusinessNftConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeBusinessNftConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = BusinessNftConfig::SPACE,
+        seeds = [b"business_nft_config"],
+        bump
+    )]
+    pub nft_config: Account<'info, BusinessNftConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the business NFT settings. NFTs stay disabled until the admin
+/// turns them on with `set_business_nft_config`.
+pub fn initialize_business_nft_config(
+    ctx: Context<InitializeBusinessNftConfig>,
+    base_uri: String,
+) -> Result<()> {
+    require!(base_uri.len() <= MAX_NFT_URI_LEN, ErrorCode::InvalidParameters);
+
+    let nft_config = &mut ctx.accounts.nft_config;
+
+    nft_config.enabled = false;
+    nft_config.base_uri = base_uri;
+    nft_config.bump = ctx.bumps.nft_config;
+    nft_config.version = BusinessNftConfig::CURRENT_VERSION;
+
+    emit!(BusinessNftConfigUpdated {
+        enabled: nft_config.enabled,
+        base_uri: nft_config.base_uri.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::CreditsMintInitialized;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeCreditsMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = CreditsMintConfig::SPACE,
+        seeds = [b"credits_mint_config"],
+        bump
+    )]
+    pub credits_mint_config: Account<'info, CreditsMintConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        seeds = [b"credits_mint"],
+        bump,
+        mint::decimals = 0,
+        mint::authority = credits_mint_config
+    )]
+    pub credits_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_credits_mint(ctx: Context<InitializeCreditsMint>, enabled: bool) -> Result<()> {
+    let config = &mut ctx.accounts.credits_mint_config;
+
+    config.mint = ctx.accounts.credits_mint.key();
+    config.enabled = enabled;
+    config.total_withdrawn = 0;
+    config.total_deposited = 0;
+    config.bump = ctx.bumps.credits_mint_config;
+    config.mint_bump = ctx.bumps.credits_mint;
+    config.version = CreditsMintConfig::CURRENT_VERSION;
+
+    emit!(CreditsMintInitialized {
+        mint: config.mint,
+        enabled,
+    });
+
+    Ok(())
+}
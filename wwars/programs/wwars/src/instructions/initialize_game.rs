@@ -1,6 +1,8 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
+use crate::migration::Versioned;
+use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
 pub struct InitializeGame<'info> {
@@ -41,20 +43,24 @@ pub struct InitializeGameParams {
     pub risk_threshold: u32,
     pub risk_growth_per_sec: u32,
     pub defend_risk_reduction_per_token: u32,
+    pub burn_bps: u16,
 }
 
 pub fn initialize_game(
     ctx: Context<InitializeGame>,
     params: InitializeGameParams,
 ) -> Result<()> {
+    require!(params.burn_bps <= 10000, ErrorCode::BurnTooHigh);
+
     let config = &mut ctx.accounts.game_config;
     let treasury_vault = &ctx.accounts.treasury_vault;
 
     config.admin = ctx.accounts.admin.key();
     config.wealth_mint = ctx.accounts.wealth_mint.key();
     config.treasury_vault = treasury_vault.key();
+    config.guardian = ctx.accounts.admin.key();
     config.fee_bps = params.fee_bps;
-    config.paused = false;
+    config.pause_flags = 0;
     config.bump_config = ctx.bumps.game_config;
     config.bump_vault = ctx.bumps.treasury_vault;
     config.default_upgrade_cd = params.default_upgrade_cd;
@@ -62,6 +68,16 @@ pub fn initialize_game(
     config.risk_threshold = params.risk_threshold;
     config.risk_growth_per_sec = params.risk_growth_per_sec;
     config.defend_risk_reduction_per_token = params.defend_risk_reduction_per_token;
+    config.burn_bps = params.burn_bps;
+    config.total_burned = 0;
+    config.version = GameConfig::CURRENT_VERSION;
+    config.attestation_mode = AttestationMode::None;
+    config.attestation_authority = Pubkey::default();
+    config.credit_transfer_fee_bps = 0;
+    config.credit_transfer_daily_limit = 0;
+    config.credit_transfer_min_age_secs = 0;
+    config.bump_wealth_escrow = 0;
+    config.current_season = 0;
 
     Ok(())
 }
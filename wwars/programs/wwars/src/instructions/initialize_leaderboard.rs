@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(season_id: u32)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"season".as_ref(), &season_id.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = Leaderboard::SPACE,
+        seeds = [b"leaderboard".as_ref(), &season_id.to_le_bytes()],
+        bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>, season_id: u32) -> Result<()> {
+    let leaderboard = &mut ctx.accounts.leaderboard;
+
+    leaderboard.season_id = season_id;
+    leaderboard.entries = Vec::new();
+    leaderboard.bump = ctx.bumps.leaderboard;
+    leaderboard.version = Leaderboard::CURRENT_VERSION;
+
+    Ok(())
+}
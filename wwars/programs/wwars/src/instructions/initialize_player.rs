@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
-use crate::state::PlayerState;
-use crate::errors::ErrorCode;
+use crate::state::{GameConfig, PlayerState};
+use crate::events::PlayerJoined;
 
 #[derive(Accounts)]
 pub struct InitializePlayer<'info> {
@@ -16,6 +16,13 @@ pub struct InitializePlayer<'info> {
     #[account(mut)]
     pub owner: Signer<'info>,
     
+    /// New players start in the current season, with nothing to carry over
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
     pub system_program: Program<'info, System>,
 }
 
@@ -23,20 +30,18 @@ pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
     let player_state = &mut ctx.accounts.player_state;
     let clock = Clock::get()?;
     
-    player_state.owner = ctx.accounts.owner.key();
-    player_state.last_work_timestamp = 0;
-    player_state.streak_count = 0;
-    player_state.work_frequency_level = 0; // Start as novice
-    player_state.total_work_actions = 0;
-    player_state.credits = 1000; // Starting credits
-    player_state.wealth_tokens = 0;
-    player_state.businesses_owned = Vec::new();
-    player_state.active_business_slots = Vec::new();
-    player_state.last_streak_check = clock.unix_timestamp;
-    player_state.cooldown_hours = 24; // Start with 24h cooldown
-    player_state.bump = ctx.bumps.player_state;
+    player_state.init(
+        ctx.accounts.owner.key(),
+        ctx.bumps.player_state,
+        clock.unix_timestamp,
+        ctx.accounts.game_config.current_season,
+    );
     
     msg!("Player initialized with 1000 starting credits");
     
+    emit!(PlayerJoined {
+        player: player_state.owner,
+    });
+    
     Ok(())
 }
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::math::validate_revenue_split;
+use crate::migration::Versioned;
+use crate::events::RevenueConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeRevenueConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = RevenueConfig::SPACE,
+        seeds = [b"revenue", game_config.key().as_ref()],
+        bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    #[account(
+        token::mint = game_config.wealth_mint,
+        constraint = rewards_vault.key() != game_config.treasury_vault @ ErrorCode::InvalidParameters
+    )]
+    pub rewards_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        token::mint = game_config.wealth_mint,
+        constraint = treasury_destination.key() != game_config.treasury_vault @ ErrorCode::InvalidParameters
+    )]
+    pub treasury_destination: Account<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct InitializeRevenueConfigParams {
+    pub rewards_bps: u16,
+    pub treasury_bps: u16,
+    pub burn_bps: u16,
+}
+
+pub fn initialize_revenue_config(
+    ctx: Context<InitializeRevenueConfig>,
+    params: InitializeRevenueConfigParams,
+) -> Result<()> {
+    validate_revenue_split(params.rewards_bps, params.treasury_bps, params.burn_bps)?;
+
+    let revenue = &mut ctx.accounts.revenue_config;
+
+    revenue.game_config = ctx.accounts.game_config.key();
+    revenue.rewards_vault = ctx.accounts.rewards_vault.key();
+    revenue.treasury_destination = ctx.accounts.treasury_destination.key();
+    revenue.rewards_bps = params.rewards_bps;
+    revenue.treasury_bps = params.treasury_bps;
+    revenue.burn_bps = params.burn_bps;
+    revenue.total_distributed = 0;
+    revenue.total_burned = 0;
+    revenue.last_distribution_ts = 0;
+    revenue.bump = ctx.bumps.revenue_config;
+    revenue.version = RevenueConfig::CURRENT_VERSION;
+
+    emit!(RevenueConfigUpdated {
+        rewards_bps: revenue.rewards_bps,
+        treasury_bps: revenue.treasury_bps,
+        burn_bps: revenue.burn_bps,
+        rewards_vault: revenue.rewards_vault,
+        treasury_destination: revenue.treasury_destination,
+    });
+
+    Ok(())
+}
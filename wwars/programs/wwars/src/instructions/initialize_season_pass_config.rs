@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::SeasonPassConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeSeasonPassConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = SeasonPassConfig::SPACE,
+        seeds = [b"season_pass_config"],
+        bump
+    )]
+    pub pass_config: Account<'info, SeasonPassConfig>,
+
+    #[account(address = game_config.wealth_mint)]
+    pub wealth_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = wealth_mint,
+        token::authority = game_config,
+        seeds = [b"pass_reward_vault", game_config.key().as_ref()],
+        bump
+    )]
+    pub reward_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_season_pass_config(
+    ctx: Context<InitializeSeasonPassConfig>,
+    premium_price: u64,
+    free_reward: u64,
+    premium_reward: u64,
+) -> Result<()> {
+    let pass_config = &mut ctx.accounts.pass_config;
+
+    pass_config.premium_price = premium_price;
+    pass_config.free_reward = free_reward;
+    pass_config.premium_reward = premium_reward;
+    pass_config.reward_vault = ctx.accounts.reward_vault.key();
+    pass_config.bump = ctx.bumps.pass_config;
+    pass_config.bump_reward_vault = ctx.bumps.reward_vault;
+    pass_config.version = SeasonPassConfig::CURRENT_VERSION;
+
+    emit!(SeasonPassConfigUpdated {
+        premium_price,
+        free_reward,
+        premium_reward,
+    });
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use anchor_spl::token::{self, Token, TokenAccount, Mint};
-use crate::state::Treasury;
+use crate::state::{Treasury, PAUSE_SWAP_CREDITS_FOR_WEALTH, PAUSE_SWAP_WEALTH_FOR_CREDITS};
+use crate::migration::Versioned;
 use crate::events::TreasuryInitialized;
 
 #[derive(Accounts)]
@@ -62,11 +63,13 @@ pub fn initialize_treasury(
     treasury.quote_vault = ctx.accounts.quote_vault.key();
     treasury.fee_bps = fee_bps;
     treasury.max_trade_units = max_trade_units;
-    treasury.paused = true; // Start paused
+    treasury.pause_flags = PAUSE_SWAP_CREDITS_FOR_WEALTH | PAUSE_SWAP_WEALTH_FOR_CREDITS; // Start with swaps paused
     treasury.min_base_liquidity = 1_000_000; // 1M WEALTH minimum
     treasury.min_quote_liquidity = 100_000;  // 100K credits minimum
     treasury.last_params_slot = clock.slot;
     treasury.params_authority = ctx.accounts.admin.key();
+    treasury.guardian = ctx.accounts.admin.key();
+    treasury.version = Treasury::CURRENT_VERSION;
     
     emit!(TreasuryInitialized {
         base_mint: treasury.base_mint,
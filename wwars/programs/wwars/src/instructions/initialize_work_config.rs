@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::math::validate_work_tables;
+use crate::migration::Versioned;
+use crate::events::WorkConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeWorkConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = WorkConfig::SPACE,
+        seeds = [b"work_config"],
+        bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_work_config(ctx: Context<InitializeWorkConfig>, tables: WorkTables) -> Result<()> {
+    validate_work_tables(&tables)?;
+
+    let work_config = &mut ctx.accounts.work_config;
+
+    work_config.tables = tables;
+    work_config.bump = ctx.bumps.work_config;
+    work_config.version = WorkConfig::CURRENT_VERSION;
+    work_config.streak_freeze_price_credits = 0;
+    work_config.streak_freeze_price_wealth = 0;
+    work_config.max_streak_freezes = 0;
+    work_config.level_decay_mode = LevelDecayMode::Reset;
+    work_config.activity_decay_bps = 0;
+
+    emit!(WorkConfigUpdated {
+        tables: work_config.tables.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::SeasonJoined;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct JoinSeason<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = PlayerSeasonStats::SPACE,
+        seeds = [b"season_stats".as_ref(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub season_stats: Account<'info, PlayerSeasonStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the player's stats for the current season. `do_work`, `takeover`
+/// and `swap_credits_for_wealth` record into it when it is passed.
+pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    require!(Clock::get()?.unix_timestamp < season.end_ts, ErrorCode::InvalidSeason);
+
+    let stats = &mut ctx.accounts.season_stats;
+
+    stats.season_id = season.id;
+    stats.player = ctx.accounts.owner.key();
+    stats.start_ts = season.start_ts;
+    stats.end_ts = season.end_ts;
+    stats.work_actions = 0;
+    stats.credits_earned = 0;
+    stats.takeovers = 0;
+    stats.wealth_earned = 0;
+    stats.bump = ctx.bumps.season_stats;
+    stats.version = PlayerSeasonStats::CURRENT_VERSION;
+
+    emit!(SeasonJoined {
+        player: stats.player,
+        season_id: stats.season_id,
+    });
+
+    Ok(())
+}
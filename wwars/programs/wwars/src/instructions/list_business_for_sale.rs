@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use crate::state::{BusinessListing, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::migration::Versioned;
+use crate::errors::ErrorCode;
+use crate::events::BusinessListed;
+use crate::instructions::purchase_business::remove_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct ListBusinessForSale<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", seller.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub seller: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        init,
+        payer = seller,
+        space = BusinessListing::SPACE,
+        seeds = [b"listing".as_ref(), seller.key().as_ref(), &[business_id]],
+        bump
+    )]
+    pub listing: Account<'info, BusinessListing>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Moves the business out of the seller's `PlayerState` into escrow.
+/// `remaining_accounts` must hold the `BusinessType` account of every owned
+/// business, in ownership order, so pending income is settled first.
+pub fn list_business_for_sale<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ListBusinessForSale<'info>>,
+    business_id: u8,
+    price: u64,
+) -> Result<()> {
+    require!(price > 0, ErrorCode::ZeroAmount);
+    
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    let level = remove_business(player, business_id)?;
+    
+    let listing = &mut ctx.accounts.listing;
+    listing.seller = ctx.accounts.seller.key();
+    listing.business_id = business_id;
+    listing.level = level;
+    listing.price = price;
+    listing.created_ts = clock.unix_timestamp;
+    listing.bump = ctx.bumps.listing;
+    listing.version = BusinessListing::CURRENT_VERSION;
+    
+    emit!(BusinessListed {
+        seller: listing.seller,
+        business_id,
+        level,
+        price,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::migration::{migrate_in_place, Versioned};
+use crate::events::AccountMigrated;
+
+/// Shared by the `migrate_*` instructions. Migration only rewrites the layout,
+/// so anyone may crank it and pay for the extra rent.
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: Decoded by `migrate_in_place`, which checks the owner and discriminator
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_account<T: Versioned>(ctx: Context<MigrateAccount>) -> Result<()> {
+    let info = ctx.accounts.account.to_account_info();
+    let clock = Clock::get()?;
+
+    let from_version = migrate_in_place::<T>(
+        &info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+    )?;
+
+    emit!(AccountMigrated {
+        account: info.key(),
+        from_version,
+        to_version: T::CURRENT_VERSION,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
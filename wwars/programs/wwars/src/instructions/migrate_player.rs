@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, Transfer};
+use crate::state::*;
+use anchor_lang::Discriminator;
+use crate::migration::{decode_zero_extended, load_any, store, Versioned};
+use crate::events::PlayerMigrated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct MigratePlayer<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: May still hold an older layout, so it is decoded by `load_any`,
+    /// or not exist yet, in which case it is created from `legacy_player`
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump
+    )]
+    pub player_state: UncheckedAccount<'info>,
+
+    /// Legacy `join_game` account, closed back to the owner once merged
+    #[account(
+        mut,
+        seeds = [b"player", owner.key().as_ref()],
+        bump = legacy_player.bump,
+        constraint = legacy_player.authority == owner.key() @ ErrorCode::Unauthorized,
+        close = owner
+    )]
+    pub legacy_player: Option<Account<'info, Player>>,
+
+    /// A player state created here starts in the current season
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Brings the player's `PlayerState` to the current layout and merges the
+/// legacy `Player` into it. A wallet that only has a legacy `Player` gets its
+/// `PlayerState` created here, so it migrates in a single transaction.
+pub fn migrate_player(ctx: Context<MigratePlayer>) -> Result<()> {
+    let info = ctx.accounts.player_state.to_account_info();
+    let clock = Clock::get()?;
+
+    let mut state = if info.data_is_empty() {
+        require!(ctx.accounts.legacy_player.is_some(), ErrorCode::InvalidParameters);
+        create_player_state(&ctx)?;
+
+        let mut state = decode_zero_extended::<PlayerState>(PlayerState::DISCRIMINATOR)?;
+        state.init(
+            ctx.accounts.owner.key(),
+            ctx.bumps.player_state,
+            clock.unix_timestamp,
+            ctx.accounts.game_config.current_season,
+        );
+        state
+    } else {
+        load_any::<PlayerState>(&info)?
+    };
+    require!(state.owner == ctx.accounts.owner.key(), ErrorCode::Unauthorized);
+
+    // Merge the legacy PvP account, if any
+    let merged_legacy_player = match &ctx.accounts.legacy_player {
+        Some(legacy) => {
+            state.last_defend_ts = state.last_defend_ts.max(legacy.last_defend_ts);
+            true
+        }
+        None => false,
+    };
+
+    let from_version = state.version;
+    require!(
+        from_version < PlayerState::CURRENT_VERSION
+            || info.data_len() < PlayerState::MAX_SIZE
+            || merged_legacy_player,
+        ErrorCode::AlreadyMigrated
+    );
+    state.version = PlayerState::CURRENT_VERSION;
+
+    store(
+        &info,
+        &ctx.accounts.owner.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        &state,
+    )?;
+
+    emit!(PlayerMigrated {
+        player: state.owner,
+        from_version,
+        to_version: state.version,
+        merged_legacy_player,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Allocates the player state PDA and hands it to the program. Lamports
+/// already sent to the address count towards its rent.
+fn create_player_state(ctx: &Context<MigratePlayer>) -> Result<()> {
+    let info = ctx.accounts.player_state.to_account_info();
+    let owner = ctx.accounts.owner.to_account_info();
+    let system = ctx.accounts.system_program.to_account_info();
+    let owner_key = ctx.accounts.owner.key();
+    let seeds = &[b"player_state".as_ref(), owner_key.as_ref(), &[ctx.bumps.player_state]];
+    let signer = &[&seeds[..]];
+
+    let required = Rent::get()?.minimum_balance(PlayerState::MAX_SIZE);
+    let top_up = required.saturating_sub(info.lamports());
+    if top_up > 0 {
+        let transfer_ctx = CpiContext::new(system.clone(), Transfer { from: owner, to: info.clone() });
+        system_program::transfer(transfer_ctx, top_up)?;
+    }
+    let allocate_ctx = CpiContext::new_with_signer(system.clone(), Allocate { account_to_allocate: info.clone() }, signer);
+    system_program::allocate(allocate_ctx, PlayerState::MAX_SIZE as u64)?;
+    let assign_ctx = CpiContext::new_with_signer(system, Assign { account_to_assign: info }, signer);
+    system_program::assign(assign_ctx, &crate::ID)
+}
@@ -1,5 +1,4 @@
 pub mod initialize_game;
-pub mod join_game;
 pub mod add_asset_class;
 pub mod buy_asset;
 pub mod queue_upgrade;
@@ -10,14 +9,62 @@ pub mod set_params;
 pub mod initialize_player;
 pub mod work;
 pub mod purchase_business;
+pub mod business_slots;
+pub mod add_business_type;
+pub mod set_business_type;
+pub mod upgrade_business;
+pub mod claim_business_income;
+pub mod sell_business;
+pub mod list_business_for_sale;
+pub mod cancel_business_listing;
+pub mod buy_business_listing;
+pub mod initialize_business_nft_config;
+pub mod set_business_nft_config;
+pub mod wrap_business;
+pub mod unwrap_business;
+pub mod purchase_business_nft;
+pub mod game_nft;
+pub mod claim_business_nft_income;
+pub mod tokenize_holding;
+pub mod claim_holding_yield;
+pub mod initialize_work_config;
+pub mod set_work_config;
+pub mod buy_streak_freeze;
+pub mod set_streak_freeze_config;
+pub mod set_level_decay_config;
+pub mod create_session;
+pub mod revoke_session;
+pub mod transfer_credits;
+pub mod initialize_credits_mint;
+pub mod set_credits_mint_config;
+pub mod deposit_credits;
+pub mod withdraw_credits;
+pub mod wealth_escrow;
+pub mod credits_token;
+pub mod create_season;
+pub mod rollover_season;
+pub mod join_season;
+pub mod initialize_season_pass_config;
+pub mod set_season_pass_config;
+pub mod open_season_pass;
+pub mod purchase_premium_pass;
+pub mod claim_pass_reward;
+pub mod open_achievements;
+pub mod initialize_leaderboard;
+pub mod update_rank;
 pub mod initialize_treasury;
 pub mod add_liquidity;
 pub mod swap_credits_for_wealth;
 pub mod swap_wealth_for_credits;
 pub mod set_treasury_params;
+pub mod set_treasury_paused;
+pub mod initialize_revenue_config;
+pub mod set_revenue_config;
+pub mod distribute_revenue;
+pub mod migrate_player;
+pub mod migrate_account;
 
 pub use initialize_game::*;
-pub use join_game::*;
 pub use add_asset_class::*;
 pub use buy_asset::*;
 pub use queue_upgrade::*;
@@ -28,8 +75,55 @@ pub use set_params::*;
 pub use initialize_player::*;
 pub use work::*;
 pub use purchase_business::*;
+pub use business_slots::*;
+pub use add_business_type::*;
+pub use set_business_type::*;
+pub use upgrade_business::*;
+pub use claim_business_income::*;
+pub use sell_business::*;
+pub use list_business_for_sale::*;
+pub use cancel_business_listing::*;
+pub use buy_business_listing::*;
+pub use initialize_business_nft_config::*;
+pub use set_business_nft_config::*;
+pub use wrap_business::*;
+pub use unwrap_business::*;
+pub use purchase_business_nft::*;
+pub use claim_business_nft_income::*;
+pub use tokenize_holding::*;
+pub use claim_holding_yield::*;
+pub use initialize_work_config::*;
+pub use set_work_config::*;
+pub use buy_streak_freeze::*;
+pub use set_streak_freeze_config::*;
+pub use set_level_decay_config::*;
+pub use create_session::*;
+pub use revoke_session::*;
+pub use transfer_credits::*;
+pub use initialize_credits_mint::*;
+pub use set_credits_mint_config::*;
+pub use deposit_credits::*;
+pub use withdraw_credits::*;
+pub use wealth_escrow::*;
+pub use create_season::*;
+pub use rollover_season::*;
+pub use join_season::*;
+pub use initialize_season_pass_config::*;
+pub use set_season_pass_config::*;
+pub use open_season_pass::*;
+pub use purchase_premium_pass::*;
+pub use claim_pass_reward::*;
+pub use open_achievements::*;
+pub use initialize_leaderboard::*;
+pub use update_rank::*;
 pub use initialize_treasury::*;
 pub use add_liquidity::*;
 pub use swap_credits_for_wealth::*;
 pub use swap_wealth_for_credits::*;
 pub use set_treasury_params::*;
+pub use set_treasury_paused::*;
+pub use initialize_revenue_config::*;
+pub use set_revenue_config::*;
+pub use distribute_revenue::*;
+pub use migrate_player::*;
+pub use migrate_account::*;
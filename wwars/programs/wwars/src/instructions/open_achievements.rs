@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::math::authorize_holding;
+use crate::migration::Versioned;
+use crate::events::AchievementUnlocked;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct OpenAchievements<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = Achievements::SPACE,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump
+    )]
+    pub achievements: Account<'info, Achievements>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Creates the achievements account and unlocks whatever the player's
+/// progress already qualifies for. `remaining_accounts` may hold the player's
+/// holdings, each tokenized one followed by the owner's token account for its
+/// NFT, so a holding already at `ACH_HOLDING_LEVEL_REQUIRED` counts too.
+/// Passed holdings must be writable.
+pub fn open_achievements<'info>(ctx: Context<'_, '_, 'info, 'info, OpenAchievements<'info>>) -> Result<()> {
+    let achievements = &mut ctx.accounts.achievements;
+    let owner = ctx.accounts.owner.key();
+    let now = Clock::get()?.unix_timestamp;
+
+    achievements.player = ctx.accounts.owner.key();
+    achievements.unlocked = 0;
+    achievements.bump = ctx.bumps.achievements;
+    achievements.version = Achievements::CURRENT_VERSION;
+
+    unlock_progress_achievements(achievements, &mut ctx.accounts.player_state, now)?;
+
+    let mut accounts = ctx.remaining_accounts.iter();
+    while let Some(info) = accounts.next() {
+        let mut holding = Account::<Holding>::try_from(info)?;
+        let nft_account = if holding.is_tokenized() {
+            let info = accounts.next().ok_or(ErrorCode::Unauthorized)?;
+            Some(InterfaceAccount::<TokenAccount>::try_from(info)?)
+        } else {
+            None
+        };
+        authorize_holding(&mut holding, &owner, nft_account.as_deref())?;
+
+        if unlock_holding_level_achievement(achievements, &mut ctx.accounts.player_state, &mut holding, now)? {
+            holding.exit(&crate::ID)?;
+            break;
+        }
+    }
+
+    Ok(())
+}
+
+/// Sets `achievement` and pays its one-time credit bonus. No-op when it is
+/// already unlocked.
+pub fn unlock_achievement(
+    achievements: &mut Achievements,
+    player: &mut PlayerState,
+    achievement: u8,
+    now: i64,
+) -> Result<()> {
+    if achievements.is_unlocked(achievement) {
+        return Ok(());
+    }
+    achievements.unlocked |= 1 << achievement;
+
+    let bonus_credits = ACHIEVEMENT_BONUS_CREDITS[achievement as usize];
+    player.credits = player.credits.checked_add(bonus_credits).ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(AchievementUnlocked {
+        player: player.owner,
+        achievement,
+        bonus_credits,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Unlocks `ACH_HOLDING_LEVEL` from `holding` if it has reached the required
+/// level and has not paid it out before, so a tokenized holding passed between
+/// wallets grants it once. Returns whether it was unlocked.
+pub fn unlock_holding_level_achievement(
+    achievements: &mut Achievements,
+    player: &mut PlayerState,
+    holding: &mut Holding,
+    now: i64,
+) -> Result<bool> {
+    if holding.level < ACH_HOLDING_LEVEL_REQUIRED
+        || holding.level_achievement_granted
+        || achievements.is_unlocked(ACH_HOLDING_LEVEL)
+    {
+        return Ok(false);
+    }
+    holding.level_achievement_granted = true;
+    unlock_achievement(achievements, player, ACH_HOLDING_LEVEL, now)?;
+    Ok(true)
+}
+
+/// Unlocks the achievements that follow from `PlayerState` alone.
+pub fn unlock_progress_achievements(
+    achievements: &mut Achievements,
+    player: &mut PlayerState,
+    now: i64,
+) -> Result<()> {
+    if !player.businesses_owned.is_empty() {
+        unlock_achievement(achievements, player, ACH_FIRST_BUSINESS, now)?;
+    }
+    if player.streak_count >= 7 {
+        unlock_achievement(achievements, player, ACH_STREAK_7, now)?;
+    }
+    if player.streak_count >= 30 {
+        unlock_achievement(achievements, player, ACH_STREAK_30, now)?;
+    }
+    if player.total_work_actions >= ACH_WORK_ACTIONS_REQUIRED {
+        unlock_achievement(achievements, player, ACH_WORK_ACTIONS, now)?;
+    }
+    Ok(())
+}
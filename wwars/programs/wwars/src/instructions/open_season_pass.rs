@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::migration::Versioned;
+use crate::events::SeasonPassOpened;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct OpenSeasonPass<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"season".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = season.bump
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = SeasonPass::SPACE,
+        seeds = [b"season_pass".as_ref(), &season.id.to_le_bytes(), owner.key().as_ref()],
+        bump
+    )]
+    pub season_pass: Account<'info, SeasonPass>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opens the free track of the current season's pass. `do_work`, `buy_asset`,
+/// `defend` and `takeover` award XP to it when it is passed.
+pub fn open_season_pass(ctx: Context<OpenSeasonPass>) -> Result<()> {
+    let season = &ctx.accounts.season;
+    require!(Clock::get()?.unix_timestamp < season.end_ts, ErrorCode::InvalidSeason);
+
+    let pass = &mut ctx.accounts.season_pass;
+
+    pass.season_id = season.id;
+    pass.player = ctx.accounts.owner.key();
+    pass.start_ts = season.start_ts;
+    pass.end_ts = season.end_ts;
+    pass.xp = 0;
+    pass.premium = false;
+    pass.claimed_free = [0; 13];
+    pass.claimed_premium = [0; 13];
+    pass.bump = ctx.bumps.season_pass;
+    pass.version = SeasonPass::CURRENT_VERSION;
+
+    emit!(SeasonPassOpened {
+        player: pass.player,
+        season_id: pass.season_id,
+    });
+
+    Ok(())
+}
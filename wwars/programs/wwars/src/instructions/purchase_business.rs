@@ -1,95 +1,217 @@
 use anchor_lang::prelude::*;
-use crate::state::PlayerState;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::{Achievements, BusinessType, CreditsMintConfig, GameConfig, PlayerState, WorkConfig, PAUSE_BUSINESS};
+use crate::math::scaled;
 use crate::errors::ErrorCode;
 use crate::events::BusinessPurchased;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+use crate::instructions::open_achievements::unlock_progress_achievements;
+use crate::instructions::credits_token::SplCredits;
 
 #[derive(Accounts)]
+#[instruction(business_id: u8)]
 pub struct PurchaseBusiness<'info> {
     #[account(
         mut,
         seeds = [b"player_state", owner.key().as_ref()],
-        bump = player_state.bump
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
     pub player_state: Account<'info, PlayerState>,
     
     #[account(mut)]
     pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_id]],
+        bump = business_type.bump,
+        constraint = business_type.enabled @ ErrorCode::BusinessDisabled
+    )]
+    pub business_type: Account<'info, BusinessType>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    /// Achievements to unlock milestones into
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+    
+    /// Passing `owner_credits_account` burns the price from SPL credits
+    /// instead of `PlayerState::credits`; the credits mint accounts and token
+    /// program must come with it
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+    
+    #[account(mut)]
+    pub credits_mint: Option<Account<'info, Mint>>,
+    
+    #[account(mut)]
+    pub owner_credits_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
 }
 
-pub fn purchase_business(ctx: Context<PurchaseBusiness>, business_id: u8) -> Result<()> {
+/// `remaining_accounts` must hold the `BusinessType` account of every owned
+/// business, in ownership order, so pending income is settled first.
+pub fn purchase_business<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PurchaseBusiness<'info>>,
+    business_id: u8,
+) -> Result<()> {
+    let spl_credits = SplCredits::resolve(
+        ctx.accounts.credits_mint_config.as_ref(),
+        ctx.accounts.credits_mint.as_ref(),
+        ctx.accounts.owner_credits_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.owner.key(),
+    )?;
     let player = &mut ctx.accounts.player_state;
     let clock = Clock::get()?;
     
-    // Validate business ID (0-19 for 20 businesses)
-    require!(business_id < 20, ErrorCode::InvalidBusinessId);
+    let business_cost = charge_business_purchase(
+        player,
+        &ctx.accounts.business_type,
+        ctx.remaining_accounts,
+        spl_credits.as_ref(),
+        &ctx.accounts.owner,
+        clock.unix_timestamp,
+    )?;
+    add_business(player, &ctx.accounts.work_config, business_id, 0);
+    
+    if let Some(achievements) = ctx.accounts.achievements.as_deref_mut() {
+        unlock_progress_achievements(achievements, player, clock.unix_timestamp)?;
+    }
+    
+    emit!(BusinessPurchased {
+        player: player.owner,
+        business_id,
+        cost: business_cost,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Checks a catalog purchase, settles pending income and deducts the price,
+/// burning it from `spl_credits` when passed. Returns the credits paid.
+pub fn charge_business_purchase<'info>(
+    player: &mut PlayerState,
+    business_type: &BusinessType,
+    remaining_accounts: &'info [AccountInfo<'info>],
+    spl_credits: Option<&SplCredits<'_, 'info>>,
+    owner: &Signer<'info>,
+    now: i64,
+) -> Result<u64> {
+    let business_id = business_type.business_id;
     
     // Check if already owned
     require!(!player.businesses_owned.contains(&business_id), ErrorCode::AlreadyOwned);
     
-    // Get business cost
-    let business_cost = get_business_cost(business_id)?;
-    require!(player.credits >= business_cost, ErrorCode::InsufficientCredits);
+    // Check level requirement
+    require!(
+        player.work_frequency_level >= business_type.required_level,
+        ErrorCode::LevelTooLow
+    );
     
-    // Deduct cost and add business
-    player.credits -= business_cost;
+    // Settle income on the current businesses so the new one does not
+    // earn for time before it was bought
+    settle_and_emit_income(player, remaining_accounts, now)?;
+    
+    // Get business cost from the catalog
+    let business_cost = business_type.cost;
+    if let Some(spl_credits) = spl_credits {
+        spl_credits.burn_from_player(player, owner.to_account_info(), &[], business_cost)?;
+    } else {
+        require!(player.credits >= business_cost, ErrorCode::InsufficientCredits);
+        player.credits -= business_cost;
+    }
+    
+    Ok(business_cost)
+}
+
+/// Adds a business at `level` and auto-assigns it to a free slot if possible.
+/// A slot the business already fills through its NFT is kept, now backed by
+/// the owned business.
+pub fn add_business(player: &mut PlayerState, work_config: &WorkConfig, business_id: u8, level: u8) {
     player.businesses_owned.push(business_id);
+    player.business_levels[business_id as usize] = level;
     
-    // Auto-assign to available slot if possible
-    let max_slots = get_max_slots(player.work_frequency_level);
+    if player.active_business_slots.contains(&business_id) {
+        player.set_nft_slot(business_id, false);
+        return;
+    }
+    let max_slots = work_config.max_slots(player.work_frequency_level);
     if player.active_business_slots.len() < max_slots as usize {
         player.active_business_slots.push(business_id);
     }
+}
+
+/// Removes an owned business from the player, including its slot and level.
+/// Returns the level it had.
+pub fn remove_business(player: &mut PlayerState, business_id: u8) -> Result<u8> {
+    require!(player.businesses_owned.contains(&business_id), ErrorCode::NotOwned);
     
-    emit!(BusinessPurchased {
-        player: player.owner,
-        business_id,
-        cost: business_cost,
-        timestamp: clock.unix_timestamp,
-    });
+    player.businesses_owned.retain(|id| *id != business_id);
+    if !player.is_nft_slot(business_id) {
+        player.active_business_slots.retain(|id| *id != business_id);
+    }
+    let level = player.business_levels[business_id as usize];
+    player.business_levels[business_id as usize] = 0;
     
-    Ok(())
+    Ok(level)
 }
 
-pub fn get_business_cost(business_id: u8) -> Result<u64> {
-    // Basic businesses (0-9): escalating costs
-    let cost = match business_id {
-        // Basic businesses
-        0 => 500,    // Lemonade Stand
-        1 => 1000,   // Food Truck
-        2 => 2500,   // Coffee Shop
-        3 => 5000,   // Retail Store
-        4 => 10000,  // Restaurant
-        5 => 20000,  // Franchise
-        6 => 50000,  // Tech Startup
-        7 => 100000, // Manufacturing
-        8 => 250000, // Real Estate
-        9 => 500000, // Investment Firm
-        
-        // Enhanced businesses (10-19): premium costs
-        10 => 1000000,  // AI Company
-        11 => 2000000,  // Biotech Lab
-        12 => 3000000,  // Space Tourism
-        13 => 5000000,  // Quantum Computing
-        14 => 7500000,  // Neural Interface
-        15 => 10000000, // Fusion Energy
-        16 => 15000000, // Nanotech Corp
-        17 => 20000000, // Time Travel Inc
-        18 => 30000000, // Multiverse LLC
-        19 => 50000000, // Reality Engine
-        
-        _ => return Err(ErrorCode::InvalidBusinessId.into()),
-    };
-    
-    Ok(cost)
+/// Credits needed to take a business from `level` to `level + 1`:
+/// the purchase price scaled by 1.5x per level.
+pub fn business_upgrade_cost(base_cost: u64, level: u8) -> Result<u64> {
+    let cost = scaled(base_cost as u128, 3, 2, level as u16 + 1).ok_or(ErrorCode::MathOverflow)?;
+    cost.try_into().map_err(|_| ErrorCode::MathOverflow.into())
 }
 
-pub fn get_max_slots(work_frequency_level: u8) -> u8 {
-    match work_frequency_level {
-        0 => 1,  // Novice: 1 slot
-        1 => 2,  // Regular: 2 slots
-        2 => 3,  // Experienced: 3 slots
-        3 => 4,  // Expert: 4 slots
-        4 => 5,  // Master: 5 slots
-        _ => 1,
+/// Loads the `BusinessType` accounts passed for `ids`, in the same order.
+pub fn load_business_types<'info>(
+    ids: &[u8],
+    accounts: &'info [AccountInfo<'info>],
+) -> Result<Vec<BusinessType>> {
+    require!(accounts.len() == ids.len(), ErrorCode::BusinessTypeMismatch);
+    ids.iter()
+        .zip(accounts)
+        .map(|(id, info)| {
+            // Program ownership and the discriminator prove it is a catalog entry;
+            // entries are only created at the PDA of their own id.
+            let business_type = Account::<BusinessType>::try_from(info)?;
+            require!(business_type.business_id == *id, ErrorCode::BusinessTypeMismatch);
+            Ok(business_type.into_inner())
+        })
+        .collect()
+}
+
+/// Drops active businesses beyond the player's current slot count, keeping the
+/// earliest activated ones. Returns the deactivated business IDs.
+pub fn trim_active_slots(player: &mut PlayerState, work_config: &WorkConfig) -> Vec<u8> {
+    let max_slots = work_config.max_slots(player.work_frequency_level) as usize;
+    if player.active_business_slots.len() <= max_slots {
+        return Vec::new();
+    }
+    let trimmed = player.active_business_slots.split_off(max_slots);
+    for business_id in &trimmed {
+        player.set_nft_slot(*business_id, false);
     }
+    trimmed
 }
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token::{self, Token};
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{BusinessNft, BusinessNftConfig, BusinessType, CreditsMintConfig, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::migration::Versioned;
+use crate::errors::ErrorCode;
+use crate::events::{BusinessPurchased, BusinessWrapped};
+use crate::instructions::purchase_business::charge_business_purchase;
+use crate::instructions::wrap_business::mint_business_nft;
+use crate::instructions::credits_token::SplCredits;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct PurchaseBusinessNft<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_id]],
+        bump = business_type.bump,
+        constraint = business_type.enabled @ ErrorCode::BusinessDisabled
+    )]
+    pub business_type: Account<'info, BusinessType>,
+    
+    #[account(
+        seeds = [b"business_nft_config"],
+        bump = nft_config.bump,
+        constraint = nft_config.enabled @ ErrorCode::BusinessNftDisabled
+    )]
+    pub nft_config: Account<'info, BusinessNftConfig>,
+    
+    /// Fresh keypair; the game config PDA is close authority, and mint
+    /// authority until the single token is minted
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = game_config,
+        mint::token_program = token_program,
+        extensions::close_authority::authority = game_config
+    )]
+    pub business_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Metaplex metadata PDA of the mint, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), business_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub business_metadata: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = business_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_nft_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = BusinessNft::SPACE,
+        seeds = [b"business_nft", business_mint.key().as_ref()],
+        bump
+    )]
+    pub business_nft: Account<'info, BusinessNft>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+    
+    /// Passing `owner_credits_account` burns the price from SPL credits
+    /// instead of `PlayerState::credits`; the credits mint accounts and token
+    /// program must come with it
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+    
+    #[account(mut)]
+    pub credits_mint: Option<Account<'info, token::Mint>>,
+    
+    #[account(mut)]
+    pub owner_credits_account: Option<Account<'info, token::TokenAccount>>,
+    
+    pub credits_token_program: Option<Program<'info, Token>>,
+}
+
+/// Buys a business from the catalog straight into an NFT instead of the
+/// player's `businesses_owned`. `remaining_accounts` must hold the
+/// `BusinessType` account of every owned business, in ownership order.
+pub fn purchase_business_nft<'info>(
+    ctx: Context<'_, '_, 'info, 'info, PurchaseBusinessNft<'info>>,
+    business_id: u8,
+) -> Result<()> {
+    let spl_credits = SplCredits::resolve(
+        ctx.accounts.credits_mint_config.as_ref(),
+        ctx.accounts.credits_mint.as_ref(),
+        ctx.accounts.owner_credits_account.as_ref(),
+        ctx.accounts.credits_token_program.as_ref(),
+        &ctx.accounts.owner.key(),
+    )?;
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    let business_cost = charge_business_purchase(
+        player,
+        &ctx.accounts.business_type,
+        ctx.remaining_accounts,
+        spl_credits.as_ref(),
+        &ctx.accounts.owner,
+        clock.unix_timestamp,
+    )?;
+    
+    mint_business_nft(
+        &ctx.accounts.game_config,
+        &ctx.accounts.nft_config,
+        &ctx.accounts.business_mint,
+        &ctx.accounts.business_metadata,
+        &ctx.accounts.owner_nft_account,
+        &ctx.accounts.owner,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_metadata_program,
+        &ctx.accounts.system_program,
+        business_id,
+    )?;
+    
+    let record = &mut ctx.accounts.business_nft;
+    record.mint = ctx.accounts.business_mint.key();
+    record.business_id = business_id;
+    record.level = 0;
+    record.minted_by = ctx.accounts.owner.key();
+    record.minted_ts = clock.unix_timestamp;
+    record.last_income_claim_ts = clock.unix_timestamp;
+    record.holder = ctx.accounts.owner.key();
+    record.bump = ctx.bumps.business_nft;
+    record.version = BusinessNft::CURRENT_VERSION;
+    
+    emit!(BusinessPurchased {
+        player: record.minted_by,
+        business_id,
+        cost: business_cost,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    emit!(BusinessWrapped {
+        player: record.minted_by,
+        business_id,
+        level: 0,
+        mint: record.mint,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
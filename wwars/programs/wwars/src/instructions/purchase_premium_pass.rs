@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::math::bps_of;
+use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct PurchasePremiumPass<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_SEASON_PASS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"season_pass_config"],
+        bump = pass_config.bump
+    )]
+    pub pass_config: Account<'info, SeasonPassConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &game_config.current_season.to_le_bytes(), owner.key().as_ref()],
+        bump = season_pass.bump,
+        constraint = !season_pass.premium @ ErrorCode::PremiumPassOwned
+    )]
+    pub season_pass: Account<'info, SeasonPass>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = game_config,
+        seeds = [b"vault", game_config.key().as_ref()],
+        bump = game_config.bump_vault
+    )]
+    pub treasury_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Unlocks the premium track of the current season's pass. Levels already
+/// reached become claimable straight away.
+pub fn purchase_premium_pass(ctx: Context<PurchasePremiumPass>) -> Result<()> {
+    let price = ctx.accounts.pass_config.premium_price;
+    require!(price > 0, ErrorCode::PremiumPassNotForSale);
+    let clock = Clock::get()?;
+
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(price, ctx.accounts.game_config.burn_bps)?;
+    let vault_amount = price.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.owner_token_account.as_ref(),
+        ctx.accounts.owner.to_account_info(),
+        &[],
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        &mut ctx.accounts.player_state,
+        price,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
+
+    let pass = &mut ctx.accounts.season_pass;
+    pass.premium = true;
+
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.owner.key(),
+            action: SpendAction::PremiumPass,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
+    emit!(PremiumPassPurchased {
+        player: pass.player,
+        season_id: pass.season_id,
+        price,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface;
 use crate::state::*;
 use crate::math::*;
 use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -13,14 +15,16 @@ pub struct QueueUpgrade<'info> {
 
     #[account(
         mut,
-        seeds = [b"player", owner.key().as_ref()],
-        bump = player.bump,
-        constraint = player.authority == owner.key() @ ErrorCode::Unauthorized
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
-    pub player: Account<'info, Player>,
+    pub player_state: Account<'info, PlayerState>,
 
     #[account(
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        mut,
+        constraint = !game_config.is_paused(PAUSE_UPGRADE) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -32,19 +36,28 @@ pub struct QueueUpgrade<'info> {
 
     #[account(
         mut,
-        seeds = [b"holding", owner.key().as_ref(), asset_class.key().as_ref()],
-        bump = holding.bump,
-        has_one = player @ ErrorCode::Unauthorized,
+        constraint = holding.class == asset_class.key() @ ErrorCode::InvalidParameters,
         constraint = holding.upgrade_end_ts == 0 @ ErrorCode::UpgradeInProgress
     )]
     pub holding: Account<'info, Holding>,
 
+    /// Token account holding the holding's NFT, required once it is tokenized
+    pub holder_nft_account: Option<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
     #[account(
         mut,
         token::mint = game_config.wealth_mint,
         token::authority = owner
     )]
-    pub owner_token_account: Account<'info, TokenAccount>,
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -55,6 +68,12 @@ pub struct QueueUpgrade<'info> {
     )]
     pub treasury_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
 }
 
@@ -64,6 +83,8 @@ pub fn queue_upgrade(ctx: Context<QueueUpgrade>, _class_id: u64) -> Result<()> {
     let game_config = &ctx.accounts.game_config;
     let clock = Clock::get()?;
 
+    authorize_holding(holding, &ctx.accounts.owner.key(), ctx.accounts.holder_nft_account.as_deref())?;
+
     // Calculate price for next level
     let next_level = holding.level.checked_add(1).ok_or(ErrorCode::MathOverflow)?;
     let price = price_for_level(
@@ -80,20 +101,46 @@ pub fn queue_upgrade(ctx: Context<QueueUpgrade>, _class_id: u64) -> Result<()> {
         .ok_or(ErrorCode::MathOverflow)?;
     let total_cost = price.checked_add(fee as u64).ok_or(ErrorCode::MathOverflow)?;
 
-    // Transfer tokens
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.owner_token_account.to_account_info(),
-        to: ctx.accounts.treasury_vault.to_account_info(),
-        authority: ctx.accounts.owner.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, total_cost)?;
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(total_cost, game_config.burn_bps)?;
+    let vault_amount = total_cost.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.owner_token_account.as_ref(),
+        ctx.accounts.owner.to_account_info(),
+        &[],
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        &mut ctx.accounts.player_state,
+        total_cost,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
 
     // Set upgrade timer
     let upgrade_cd = upgrade_cd(asset_class, game_config);
     holding.upgrade_end_ts = clock.unix_timestamp.checked_add(upgrade_cd).ok_or(ErrorCode::MathOverflow)?;
 
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.owner.key(),
+            action: SpendAction::QueueUpgrade,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     emit!(UpgradeQueued {
         player: holding.player,
         class: holding.class,
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Revoke, Token, TokenAccount};
+use crate::state::*;
+use crate::events::SessionRevoked;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct RevokeSession<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"session", owner.key().as_ref(), session.session_signer.as_ref()],
+        bump = session.bump,
+        has_one = owner @ ErrorCode::Unauthorized,
+        close = owner
+    )]
+    pub session: Account<'info, SessionKey>,
+
+    /// WEALTH account to clear the session's delegation from
+    #[account(
+        mut,
+        token::authority = owner
+    )]
+    pub owner_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// SPL credits account to clear the session's delegation from
+    #[account(
+        mut,
+        token::authority = owner
+    )]
+    pub owner_credits_account: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Closes a session, including an expired one, and drops its delegations.
+pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+    let session = &ctx.accounts.session;
+
+    let delegated_accounts = [&ctx.accounts.owner_token_account, &ctx.accounts.owner_credits_account];
+    for token_account in delegated_accounts.into_iter().flatten() {
+        if token_account.delegate == Some(session.key()).into() {
+            let cpi_accounts = Revoke {
+                source: token_account.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            };
+            let cpi_program = ctx.accounts.token_program.to_account_info();
+            let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
+            token::revoke(cpi_ctx)?;
+        }
+    }
+
+    emit!(SessionRevoked {
+        owner: session.owner,
+        session_signer: session.session_signer,
+        credits_spent: session.credits_spent,
+        wealth_spent: session.wealth_spent,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
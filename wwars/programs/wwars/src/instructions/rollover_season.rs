@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::math::bps_of;
+use crate::events::{ActiveBusinessesChanged, SeasonCarryOver, SeasonStarted};
+use crate::errors::ErrorCode;
+use crate::instructions::purchase_business::trim_active_slots;
+
+#[derive(Accounts)]
+pub struct RolloverSeason<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+
+    /// The current season, or the next one once it has started
+    #[account(
+        mut,
+        seeds = [b"season".as_ref(), &season.id.to_le_bytes()],
+        bump = season.bump,
+        constraint = season.id == game_config.current_season
+            || season.id == game_config.current_season.saturating_add(1) @ ErrorCode::InvalidSeason
+    )]
+    pub season: Account<'info, Season>,
+}
+
+/// Permissionless crank. Starts `season` if it is the next one, then applies
+/// its carry-over rules to every `PlayerState` in `remaining_accounts` that
+/// has not entered it yet. Call again with further batches of players.
+/// Players cannot act until they have been rolled, and may roll themselves.
+pub fn rollover_season<'info>(ctx: Context<'_, '_, 'info, 'info, RolloverSeason<'info>>) -> Result<()> {
+    let game_config = &mut ctx.accounts.game_config;
+    let season = &mut ctx.accounts.season;
+    let now = Clock::get()?.unix_timestamp;
+
+    if season.id != game_config.current_season {
+        require!(now >= season.start_ts, ErrorCode::SeasonNotStarted);
+        let previous = game_config.current_season;
+        game_config.current_season = season.id;
+
+        emit!(SeasonStarted {
+            id: season.id,
+            previous,
+            timestamp: now,
+        });
+    }
+
+    for info in ctx.remaining_accounts.iter() {
+        let mut player = Account::<PlayerState>::try_from(info)?;
+        if player.season_id >= season.id {
+            continue;
+        }
+        apply_carry_over(&mut player, season, &ctx.accounts.work_config, now)?;
+        player.exit(&crate::ID)?;
+        season.players_rolled = season.players_rolled.saturating_add(1);
+    }
+
+    Ok(())
+}
+
+fn apply_carry_over(player: &mut PlayerState, season: &Season, work_config: &WorkConfig, now: i64) -> Result<()> {
+    player.season_id = season.id;
+
+    // Accounts created during the season have nothing to carry over
+    if player.created_ts >= season.start_ts {
+        return Ok(());
+    }
+
+    let rules = &season.rules;
+    let credits_before = player.credits;
+    let level_before = player.work_frequency_level;
+
+    player.credits = bps_of(player.credits, rules.credits_keep_bps)?;
+    player.bridged_credits = bps_of(player.bridged_credits, rules.credits_keep_bps)?;
+
+    let level = level_before.saturating_sub(rules.level_drop);
+    if level < level_before {
+        // Keep the streak consistent with the level so the next do_work
+        // does not restore it
+        let threshold = work_config.tables.streak_thresholds[level as usize];
+        player.streak_count = player.streak_count.min(threshold);
+        player.work_frequency_level = level;
+        player.cooldown_hours = work_config.cooldown_hours(level);
+
+        let trimmed = trim_active_slots(player, work_config);
+        if !trimmed.is_empty() {
+            emit!(ActiveBusinessesChanged {
+                player: player.owner,
+                activated: None,
+                deactivated: trimmed,
+                active_business_slots: player.active_business_slots.clone(),
+                timestamp: now,
+            });
+        }
+    }
+
+    if rules.clear_streak_freezes {
+        player.streak_freezes = 0;
+    }
+
+    emit!(SeasonCarryOver {
+        player: player.owner,
+        season_id: season.id,
+        credits_before,
+        credits_after: player.credits,
+        level_before,
+        level_after: player.work_frequency_level,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::{BusinessType, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::math::bps_of;
+use crate::errors::ErrorCode;
+use crate::events::BusinessSold;
+use crate::instructions::purchase_business::remove_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+
+// Share of the catalog price refunded when selling back to the game (50%)
+pub const SELL_REFUND_BPS: u16 = 5000;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct SellBusiness<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_id]],
+        bump = business_type.bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+}
+
+/// `remaining_accounts` must hold the `BusinessType` account of every owned
+/// business, in ownership order, so pending income is settled first.
+pub fn sell_business<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SellBusiness<'info>>,
+    business_id: u8,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    remove_business(player, business_id)?;
+    
+    let refund = bps_of(ctx.accounts.business_type.cost, SELL_REFUND_BPS)?;
+    player.credits = player.credits.checked_add(refund).ok_or(ErrorCode::MathOverflow)?;
+    
+    emit!(BusinessSold {
+        player: player.owner,
+        business_id,
+        refund,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
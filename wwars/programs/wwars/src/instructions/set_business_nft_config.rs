@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::BusinessNftConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetBusinessNftConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"business_nft_config"],
+        bump = nft_config.bump
+    )]
+    pub nft_config: Account<'info, BusinessNftConfig>,
+}
+
+pub fn set_business_nft_config(
+    ctx: Context<SetBusinessNftConfig>,
+    enabled: Option<bool>,
+    base_uri: Option<String>,
+) -> Result<()> {
+    let nft_config = &mut ctx.accounts.nft_config;
+
+    if let Some(enabled) = enabled {
+        nft_config.enabled = enabled;
+    }
+    if let Some(base_uri) = base_uri {
+        require!(base_uri.len() <= MAX_NFT_URI_LEN, ErrorCode::InvalidParameters);
+        nft_config.base_uri = base_uri;
+    }
+
+    emit!(BusinessNftConfigUpdated {
+        enabled: nft_config.enabled,
+        base_uri: nft_config.base_uri.clone(),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::BusinessTypeUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct SetBusinessType<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"business".as_ref(), &[business_id]],
+        bump = business_type.bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetBusinessTypeArgs {
+    pub cost: Option<u64>,
+    pub name_hash: Option<[u8; 32]>,
+    pub work_multiplier_bps: Option<u16>,
+    pub flat_bonus: Option<u64>,
+    pub set_id: Option<u8>,
+    pub set_bonus_bps: Option<u16>,
+    pub passive_income_per_hour: Option<u64>,
+    pub required_level: Option<u8>,
+    pub enabled: Option<bool>,
+}
+
+pub fn set_business_type(
+    ctx: Context<SetBusinessType>,
+    _business_id: u8,
+    args: SetBusinessTypeArgs,
+) -> Result<()> {
+    let business_type = &mut ctx.accounts.business_type;
+
+    if let Some(cost) = args.cost {
+        require!(cost > 0, ErrorCode::ZeroAmount);
+        business_type.cost = cost;
+    }
+    if let Some(name_hash) = args.name_hash {
+        business_type.name_hash = name_hash;
+    }
+    if let Some(work_multiplier_bps) = args.work_multiplier_bps {
+        business_type.work_multiplier_bps = work_multiplier_bps;
+    }
+    if let Some(flat_bonus) = args.flat_bonus {
+        business_type.flat_bonus = flat_bonus;
+    }
+    if let Some(set_id) = args.set_id {
+        business_type.set_id = set_id;
+    }
+    if let Some(set_bonus_bps) = args.set_bonus_bps {
+        business_type.set_bonus_bps = set_bonus_bps;
+    }
+    if let Some(passive_income_per_hour) = args.passive_income_per_hour {
+        business_type.passive_income_per_hour = passive_income_per_hour;
+    }
+    if let Some(required_level) = args.required_level {
+        business_type.required_level = required_level;
+    }
+    if let Some(enabled) = args.enabled {
+        business_type.enabled = enabled;
+    }
+
+    emit!(BusinessTypeUpdated {
+        business_id: business_type.business_id,
+        cost: business_type.cost,
+        work_multiplier_bps: business_type.work_multiplier_bps,
+        flat_bonus: business_type.flat_bonus,
+        set_id: business_type.set_id,
+        set_bonus_bps: business_type.set_bonus_bps,
+        passive_income_per_hour: business_type.passive_income_per_hour,
+        required_level: business_type.required_level,
+        enabled: business_type.enabled,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::CreditsMintConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetCreditsMintConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Account<'info, CreditsMintConfig>,
+}
+
+pub fn set_credits_mint_config(ctx: Context<SetCreditsMintConfig>, enabled: bool) -> Result<()> {
+    ctx.accounts.credits_mint_config.enabled = enabled;
+
+    emit!(CreditsMintConfigUpdated { enabled });
+
+    Ok(())
+}
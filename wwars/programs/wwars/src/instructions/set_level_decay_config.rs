@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::LevelDecayConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetLevelDecayConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+}
+
+pub fn set_level_decay_config(
+    ctx: Context<SetLevelDecayConfig>,
+    mode: LevelDecayMode,
+    activity_decay_bps: u16,
+) -> Result<()> {
+    require!(activity_decay_bps <= 10000, ErrorCode::InvalidParameters);
+
+    let work_config = &mut ctx.accounts.work_config;
+
+    work_config.level_decay_mode = mode;
+    work_config.activity_decay_bps = activity_decay_bps;
+
+    emit!(LevelDecayConfigUpdated {
+        mode,
+        activity_decay_bps,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,5 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
+use crate::math::next_pause_flags;
+use crate::events::PauseFlagsUpdated;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -22,6 +24,13 @@ pub struct SetParamsArgs {
     pub risk_threshold: Option<u32>,
     pub risk_growth_per_sec: Option<u32>,
     pub defend_risk_reduction_per_token: Option<u32>,
+    pub guardian: Option<Pubkey>,
+    pub burn_bps: Option<u16>,
+    pub attestation_mode: Option<AttestationMode>,
+    pub attestation_authority: Option<Pubkey>,
+    pub credit_transfer_fee_bps: Option<u16>,
+    pub credit_transfer_daily_limit: Option<u64>,
+    pub credit_transfer_min_age_secs: Option<i64>,
 }
 
 pub fn set_params(ctx: Context<SetParams>, args: SetParamsArgs) -> Result<()> {
@@ -45,6 +54,35 @@ pub fn set_params(ctx: Context<SetParams>, args: SetParamsArgs) -> Result<()> {
     if let Some(defend_risk_reduction_per_token) = args.defend_risk_reduction_per_token {
         config.defend_risk_reduction_per_token = defend_risk_reduction_per_token;
     }
+    if let Some(guardian) = args.guardian {
+        config.guardian = guardian;
+    }
+    if let Some(burn_bps) = args.burn_bps {
+        require!(burn_bps <= 10000, ErrorCode::BurnTooHigh);
+        config.burn_bps = burn_bps;
+    }
+    if let Some(attestation_mode) = args.attestation_mode {
+        config.attestation_mode = attestation_mode;
+    }
+    if let Some(attestation_authority) = args.attestation_authority {
+        config.attestation_authority = attestation_authority;
+    }
+    if let Some(credit_transfer_fee_bps) = args.credit_transfer_fee_bps {
+        require!(credit_transfer_fee_bps <= 10000, ErrorCode::FeeTooHigh);
+        config.credit_transfer_fee_bps = credit_transfer_fee_bps;
+    }
+    if let Some(credit_transfer_daily_limit) = args.credit_transfer_daily_limit {
+        config.credit_transfer_daily_limit = credit_transfer_daily_limit;
+    }
+    if let Some(credit_transfer_min_age_secs) = args.credit_transfer_min_age_secs {
+        require!(credit_transfer_min_age_secs >= 0, ErrorCode::InvalidParameters);
+        config.credit_transfer_min_age_secs = credit_transfer_min_age_secs;
+    }
+    require!(
+        config.attestation_mode == AttestationMode::None
+            || config.attestation_authority != Pubkey::default(),
+        ErrorCode::InvalidParameters
+    );
 
     Ok(())
 }
@@ -52,17 +90,36 @@ pub fn set_params(ctx: Context<SetParams>, args: SetParamsArgs) -> Result<()> {
 #[derive(Accounts)]
 pub struct Pause<'info> {
     #[account(mut)]
-    pub admin: Signer<'info>,
+    pub authority: Signer<'info>,
 
     #[account(
         mut,
-        has_one = admin @ ErrorCode::Unauthorized
+        constraint = authority.key() == game_config.admin
+            || authority.key() == game_config.guardian @ ErrorCode::Unauthorized
     )]
     pub game_config: Account<'info, GameConfig>,
 }
 
-pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
+pub fn pause(ctx: Context<Pause>, pause_flags: u16) -> Result<()> {
     let config = &mut ctx.accounts.game_config;
-    config.paused = paused;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+
+    let old_flags = config.pause_flags;
+    config.pause_flags = next_pause_flags(
+        old_flags,
+        pause_flags,
+        GAME_PAUSE_MASK,
+        authority == config.admin,
+    )?;
+
+    emit!(PauseFlagsUpdated {
+        target: config.key(),
+        authority,
+        old_flags,
+        new_flags: config.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+
     Ok(())
 }
@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::math::validate_revenue_split;
+use crate::events::RevenueConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetRevenueConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"revenue", game_config.key().as_ref()],
+        bump = revenue_config.bump
+    )]
+    pub revenue_config: Account<'info, RevenueConfig>,
+
+    /// New rewards pool, if it is being replaced
+    #[account(
+        token::mint = game_config.wealth_mint,
+        constraint = rewards_vault.key() != game_config.treasury_vault @ ErrorCode::InvalidParameters
+    )]
+    pub rewards_vault: Option<Account<'info, TokenAccount>>,
+
+    /// New treasury destination, if it is being replaced
+    #[account(
+        token::mint = game_config.wealth_mint,
+        constraint = treasury_destination.key() != game_config.treasury_vault @ ErrorCode::InvalidParameters
+    )]
+    pub treasury_destination: Option<Account<'info, TokenAccount>>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRevenueConfigArgs {
+    pub rewards_bps: Option<u16>,
+    pub treasury_bps: Option<u16>,
+    pub burn_bps: Option<u16>,
+}
+
+pub fn set_revenue_config(ctx: Context<SetRevenueConfig>, args: SetRevenueConfigArgs) -> Result<()> {
+    let revenue = &mut ctx.accounts.revenue_config;
+
+    if let Some(rewards_bps) = args.rewards_bps {
+        revenue.rewards_bps = rewards_bps;
+    }
+    if let Some(treasury_bps) = args.treasury_bps {
+        revenue.treasury_bps = treasury_bps;
+    }
+    if let Some(burn_bps) = args.burn_bps {
+        revenue.burn_bps = burn_bps;
+    }
+    validate_revenue_split(revenue.rewards_bps, revenue.treasury_bps, revenue.burn_bps)?;
+
+    if let Some(rewards_vault) = &ctx.accounts.rewards_vault {
+        revenue.rewards_vault = rewards_vault.key();
+    }
+    if let Some(treasury_destination) = &ctx.accounts.treasury_destination {
+        revenue.treasury_destination = treasury_destination.key();
+    }
+
+    emit!(RevenueConfigUpdated {
+        rewards_bps: revenue.rewards_bps,
+        treasury_bps: revenue.treasury_bps,
+        burn_bps: revenue.burn_bps,
+        rewards_vault: revenue.rewards_vault,
+        treasury_destination: revenue.treasury_destination,
+    });
+
+    Ok(())
+}
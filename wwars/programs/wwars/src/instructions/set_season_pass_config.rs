@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::SeasonPassConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetSeasonPassConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"season_pass_config"],
+        bump = pass_config.bump
+    )]
+    pub pass_config: Account<'info, SeasonPassConfig>,
+}
+
+pub fn set_season_pass_config(
+    ctx: Context<SetSeasonPassConfig>,
+    premium_price: Option<u64>,
+    free_reward: Option<u64>,
+    premium_reward: Option<u64>,
+) -> Result<()> {
+    let pass_config = &mut ctx.accounts.pass_config;
+
+    if let Some(premium_price) = premium_price {
+        pass_config.premium_price = premium_price;
+    }
+    if let Some(free_reward) = free_reward {
+        pass_config.free_reward = free_reward;
+    }
+    if let Some(premium_reward) = premium_reward {
+        pass_config.premium_reward = premium_reward;
+    }
+
+    emit!(SeasonPassConfigUpdated {
+        premium_price: pass_config.premium_price,
+        free_reward: pass_config.free_reward,
+        premium_reward: pass_config.premium_reward,
+    });
+
+    Ok(())
+}
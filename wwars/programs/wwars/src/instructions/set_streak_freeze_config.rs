@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::StreakFreezeConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetStreakFreezeConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+}
+
+/// Sets streak freeze prices and the per-player cap. A zero price stops
+/// sales in that currency.
+pub fn set_streak_freeze_config(
+    ctx: Context<SetStreakFreezeConfig>,
+    price_credits: u64,
+    price_wealth: u64,
+    max_streak_freezes: u8,
+) -> Result<()> {
+    let work_config = &mut ctx.accounts.work_config;
+
+    work_config.streak_freeze_price_credits = price_credits;
+    work_config.streak_freeze_price_wealth = price_wealth;
+    work_config.max_streak_freezes = max_streak_freezes;
+
+    emit!(StreakFreezeConfigUpdated {
+        price_credits,
+        price_wealth,
+        max_streak_freezes,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
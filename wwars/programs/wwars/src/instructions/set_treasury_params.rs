@@ -20,7 +20,7 @@ pub fn set_treasury_params(
     ctx: Context<SetTreasuryParams>,
     fee_bps: Option<u16>,
     max_trade_units: Option<u64>,
-    paused: Option<bool>,
+    guardian: Option<Pubkey>,
 ) -> Result<()> {
     let treasury = &mut ctx.accounts.treasury;
     let clock = Clock::get()?;
@@ -44,8 +44,8 @@ pub fn set_treasury_params(
         treasury.max_trade_units = new_max;
     }
     
-    if let Some(new_paused) = paused {
-        treasury.paused = new_paused;
+    if let Some(new_guardian) = guardian {
+        treasury.guardian = new_guardian;
     }
     
     treasury.last_params_slot = clock.slot;
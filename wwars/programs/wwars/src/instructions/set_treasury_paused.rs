@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::{Treasury, TREASURY_PAUSE_MASK};
+use crate::math::next_pause_flags;
+use crate::events::PauseFlagsUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetTreasuryPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump,
+        constraint = authority.key() == treasury.params_authority
+            || authority.key() == treasury.guardian @ ErrorCode::Unauthorized
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn set_treasury_paused(ctx: Context<SetTreasuryPaused>, pause_flags: u16) -> Result<()> {
+    let treasury = &mut ctx.accounts.treasury;
+    let authority = ctx.accounts.authority.key();
+    let clock = Clock::get()?;
+    
+    let old_flags = treasury.pause_flags;
+    treasury.pause_flags = next_pause_flags(
+        old_flags,
+        pause_flags,
+        TREASURY_PAUSE_MASK,
+        authority == treasury.params_authority,
+    )?;
+    treasury.last_params_slot = clock.slot;
+    
+    emit!(PauseFlagsUpdated {
+        target: treasury.key(),
+        authority,
+        old_flags,
+        new_flags: treasury.pause_flags,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::math::validate_work_tables;
+use crate::events::WorkConfigUpdated;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct SetWorkConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+}
+
+/// Replaces the work tables. Takes effect on each player's next work action.
+pub fn set_work_config(ctx: Context<SetWorkConfig>, tables: WorkTables) -> Result<()> {
+    validate_work_tables(&tables)?;
+
+    let work_config = &mut ctx.accounts.work_config;
+    work_config.tables = tables;
+
+    emit!(WorkConfigUpdated {
+        tables: work_config.tables.clone(),
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
-use crate::state::{Treasury, PlayerState};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::{Treasury, GameConfig, PlayerState, PlayerSeasonStats, SessionKey, CreditsMintConfig, SESSION_SWAP_CREDITS_FOR_WEALTH, PAUSE_SWAP_CREDITS_FOR_WEALTH};
+use crate::math::authorize_player;
 use crate::events::SwapExecuted;
 use crate::errors::ErrorCode;
+use crate::instructions::credits_token::SplCredits;
 
 #[derive(Accounts)]
 pub struct SwapCreditsForWealth<'info> {
@@ -16,12 +18,23 @@ pub struct SwapCreditsForWealth<'info> {
     #[account(
         mut,
         seeds = [b"player_state", user.key().as_ref()],
-        bump = player_state.bump
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
     pub player_state: Account<'info, PlayerState>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    /// CHECK: Player wallet; signs itself or through `session`
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub user: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
     
     #[account(
         mut,
@@ -45,6 +58,36 @@ pub struct SwapCreditsForWealth<'info> {
     pub user_base_account: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", user.key().as_ref(), signer.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+    
+    /// Required with `credits_mint_config` when `quote_mint` is the credits
+    /// mint, so the vault stays backed, or when paying with SPL credits
+    #[account(mut)]
+    pub credits_mint: Option<Account<'info, Mint>>,
+    
+    /// Pays the credits as SPL tokens instead of `PlayerState::credits`
+    #[account(mut)]
+    pub user_credits_account: Option<Account<'info, TokenAccount>>,
+    
+    /// Current season stats; recorded into while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_stats".as_ref(), &season_stats.season_id.to_le_bytes(), user.key().as_ref()],
+        bump = season_stats.bump
+    )]
+    pub season_stats: Option<Account<'info, PlayerSeasonStats>>,
 }
 
 pub fn swap_credits_for_wealth(
@@ -52,14 +95,35 @@ pub fn swap_credits_for_wealth(
     credits_in: u64,
     min_wealth_out: u64,
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    let user_key = ctx.accounts.user.key();
+    let signer_key = ctx.accounts.signer.key();
+    let via_session = authorize_player(
+        &user_key,
+        &signer_key,
+        ctx.accounts.session.as_deref_mut(),
+        SESSION_SWAP_CREDITS_FOR_WEALTH,
+        credits_in,
+        0,
+        clock.unix_timestamp,
+    )?;
+    
+    let spl_credits = SplCredits::resolve(
+        ctx.accounts.credits_mint_config.as_ref(),
+        ctx.accounts.credits_mint.as_ref(),
+        ctx.accounts.user_credits_account.as_ref(),
+        Some(&ctx.accounts.token_program),
+        &user_key,
+    )?;
     let treasury = &ctx.accounts.treasury;
     let player = &mut ctx.accounts.player_state;
-    let clock = Clock::get()?;
     
     // Validation checks
-    require!(!treasury.paused, ErrorCode::TreasuryPaused);
+    require!(!treasury.is_paused(PAUSE_SWAP_CREDITS_FOR_WEALTH), ErrorCode::TreasuryPaused);
     require!(credits_in > 0, ErrorCode::ZeroAmount);
-    require!(player.credits >= credits_in, ErrorCode::InsufficientCredits);
+    if spl_credits.is_none() {
+        require!(player.credits >= credits_in, ErrorCode::InsufficientCredits);
+    }
     require!(credits_in <= treasury.max_trade_units, ErrorCode::TradeTooLarge);
     
     // Get current reserves
@@ -91,9 +155,6 @@ pub fn swap_credits_for_wealth(
     let price_before = if r_base > 0 { (r_quote * 1_000_000u128) / r_base } else { 0 };
     let price_after = if r_base_new > 0 { (r_quote_new * 1_000_000u128) / r_base_new } else { 0 };
     
-    // Update player credits (deduct the credits used)
-    player.credits -= credits_in;
-    
     // Transfer WEALTH tokens from vault to user
     let treasury_seeds = &[
         b"treasury",
@@ -112,8 +173,60 @@ pub fn swap_credits_for_wealth(
     );
     token::transfer(transfer_ctx, wealth_out as u64)?;
     
-    // Update virtual quote vault (credits are virtual, not real tokens)
-    // Note: In a real implementation, you might want to track virtual reserves separately
+    // Take the credits: SPL credits are spent by the user or their session
+    // PDA as token delegate, ledger credits are deducted
+    let (spend_authority, session_bump) = match &ctx.accounts.session {
+        Some(session) if via_session => (session.to_account_info(), session.bump),
+        _ => (ctx.accounts.user.to_account_info(), 0),
+    };
+    let session_seeds = &[b"session".as_ref(), user_key.as_ref(), signer_key.as_ref(), &[session_bump]];
+    let session_signer = &[&session_seeds[..]];
+    let spend_signer: &[&[&[u8]]] = if via_session { session_signer } else { &[] };
+    
+    // With a credits mint, the spent credits become real quote reserve;
+    // skipping this would let swaps move the price without backing. SPL
+    // credits move into the vault, ledger credits are minted there.
+    if ctx.accounts.treasury.quote_mint == CreditsMintConfig::mint_address() {
+        let credits_mint_config = ctx.accounts.credits_mint_config.as_ref()
+            .ok_or(ErrorCode::CreditsMintAccountsMissing)?;
+        let credits_mint = ctx.accounts.credits_mint.as_ref()
+            .ok_or(ErrorCode::CreditsMintAccountsMissing)?;
+        require!(credits_mint.key() == credits_mint_config.mint, ErrorCode::InvalidParameters);
+        if let Some(spl_credits) = &spl_credits {
+            spl_credits.transfer_from_player(
+                player,
+                ctx.accounts.quote_vault.to_account_info(),
+                spend_authority,
+                spend_signer,
+                credits_in,
+            )?;
+        } else {
+            let config_seeds = &[b"credits_mint_config".as_ref(), &[credits_mint_config.bump]];
+            let config_signer = &[&config_seeds[..]];
+            let mint_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::MintTo {
+                    mint: credits_mint.to_account_info(),
+                    to: ctx.accounts.quote_vault.to_account_info(),
+                    authority: credits_mint_config.to_account_info(),
+                },
+                config_signer,
+            );
+            token::mint_to(mint_ctx, credits_in)?;
+        }
+    } else if let Some(spl_credits) = &spl_credits {
+        spl_credits.burn_from_player(player, spend_authority, spend_signer, credits_in)?;
+    }
+    
+    if spl_credits.is_none() {
+        player.credits -= credits_in;
+    }
+    
+    if let Some(stats) = ctx.accounts.season_stats.as_deref_mut() {
+        if stats.is_live(clock.unix_timestamp) {
+            stats.wealth_earned = stats.wealth_earned.saturating_add(wealth_out as u64);
+        }
+    }
     
     emit!(SwapExecuted {
         user: ctx.accounts.user.key(),
@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount};
-use crate::state::{Treasury, PlayerState};
+use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use crate::state::{Treasury, GameConfig, PlayerState, SessionKey, CreditsMintConfig, SESSION_SWAP_WEALTH_FOR_CREDITS, PAUSE_SWAP_WEALTH_FOR_CREDITS};
+use crate::math::authorize_player;
 use crate::events::SwapExecuted;
 use crate::errors::ErrorCode;
+use crate::instructions::credits_token::SplCredits;
 
 #[derive(Accounts)]
 pub struct SwapWealthForCredits<'info> {
@@ -16,12 +18,23 @@ pub struct SwapWealthForCredits<'info> {
     #[account(
         mut,
         seeds = [b"player_state", user.key().as_ref()],
-        bump = player_state.bump
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
     pub player_state: Account<'info, PlayerState>,
     
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    /// CHECK: Player wallet; signs itself or through `session`
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub signer: Signer<'info>,
     
     #[account(
         mut,
@@ -45,6 +58,28 @@ pub struct SwapWealthForCredits<'info> {
     pub user_base_account: Account<'info, TokenAccount>,
     
     pub token_program: Program<'info, Token>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", user.key().as_ref(), signer.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+    
+    /// Required with `credits_mint_config` when `quote_mint` is the credits
+    /// mint, so the vault stays backed, or when paying out SPL credits
+    #[account(mut)]
+    pub credits_mint: Option<Account<'info, Mint>>,
+    
+    /// Receives the credits as SPL tokens instead of `PlayerState::credits`
+    #[account(mut)]
+    pub user_credits_account: Option<Account<'info, TokenAccount>>,
 }
 
 pub fn swap_wealth_for_credits(
@@ -52,12 +87,31 @@ pub fn swap_wealth_for_credits(
     wealth_in: u64,
     min_credits_out: u64,
 ) -> Result<()> {
+    let clock = Clock::get()?;
+    let user_key = ctx.accounts.user.key();
+    let signer_key = ctx.accounts.signer.key();
+    let via_session = authorize_player(
+        &user_key,
+        &signer_key,
+        ctx.accounts.session.as_deref_mut(),
+        SESSION_SWAP_WEALTH_FOR_CREDITS,
+        0,
+        wealth_in,
+        clock.unix_timestamp,
+    )?;
+    
+    let spl_credits = SplCredits::resolve(
+        ctx.accounts.credits_mint_config.as_ref(),
+        ctx.accounts.credits_mint.as_ref(),
+        ctx.accounts.user_credits_account.as_ref(),
+        Some(&ctx.accounts.token_program),
+        &user_key,
+    )?;
     let treasury = &ctx.accounts.treasury;
     let player = &mut ctx.accounts.player_state;
-    let clock = Clock::get()?;
     
     // Validation checks
-    require!(!treasury.paused, ErrorCode::TreasuryPaused);
+    require!(!treasury.is_paused(PAUSE_SWAP_WEALTH_FOR_CREDITS), ErrorCode::TreasuryPaused);
     require!(wealth_in > 0, ErrorCode::ZeroAmount);
     require!(wealth_in <= treasury.max_trade_units, ErrorCode::TradeTooLarge);
     
@@ -93,19 +147,70 @@ pub fn swap_wealth_for_credits(
     let price_before = if r_base > 0 { (r_quote * 1_000_000u128) / r_base } else { 0 };
     let price_after = if r_base_new > 0 { (r_quote_new * 1_000_000u128) / r_base_new } else { 0 };
     
-    // Transfer WEALTH tokens from user to vault
-    let transfer_ctx = CpiContext::new(
+    // Transfer WEALTH tokens from user to vault; session spends go through
+    // the session PDA as token delegate
+    let (spend_authority, session_bump) = match &ctx.accounts.session {
+        Some(session) if via_session => (session.to_account_info(), session.bump),
+        _ => (ctx.accounts.user.to_account_info(), 0),
+    };
+    let session_seeds = &[b"session".as_ref(), user_key.as_ref(), signer_key.as_ref(), &[session_bump]];
+    let session_signer = &[&session_seeds[..]];
+    let spend_signer: &[&[&[u8]]] = if via_session { session_signer } else { &[] };
+    
+    let transfer_ctx = CpiContext::new_with_signer(
         ctx.accounts.token_program.to_account_info(),
         token::Transfer {
             from: ctx.accounts.user_base_account.to_account_info(),
             to: ctx.accounts.base_vault.to_account_info(),
-            authority: ctx.accounts.user.to_account_info(),
+            authority: spend_authority,
         },
+        spend_signer,
     );
     token::transfer(transfer_ctx, wealth_in)?;
     
+    // With a credits mint, the credits paid out leave the quote reserve;
+    // skipping this would let swaps move the price without backing. SPL
+    // payouts move them to the user, ledger payouts burn them.
+    if ctx.accounts.treasury.quote_mint == CreditsMintConfig::mint_address() {
+        let credits_mint_config = ctx.accounts.credits_mint_config.as_ref()
+            .ok_or(ErrorCode::CreditsMintAccountsMissing)?;
+        let credits_mint = ctx.accounts.credits_mint.as_ref()
+            .ok_or(ErrorCode::CreditsMintAccountsMissing)?;
+        require!(credits_mint.key() == credits_mint_config.mint, ErrorCode::InvalidParameters);
+        let treasury_seeds = &[b"treasury".as_ref(), &[ctx.accounts.treasury.bump]];
+        let treasury_signer = &[&treasury_seeds[..]];
+        if let Some(spl_credits) = &spl_credits {
+            let transfer_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.quote_vault.to_account_info(),
+                    to: spl_credits.account.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                treasury_signer,
+            );
+            token::transfer(transfer_ctx, credits_out as u64)?;
+            player.bridged_credits = player.bridged_credits.checked_add(credits_out as u64).ok_or(ErrorCode::MathOverflow)?;
+        } else {
+            let burn_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Burn {
+                    mint: credits_mint.to_account_info(),
+                    from: ctx.accounts.quote_vault.to_account_info(),
+                    authority: ctx.accounts.treasury.to_account_info(),
+                },
+                treasury_signer,
+            );
+            token::burn(burn_ctx, credits_out as u64)?;
+        }
+    } else if let Some(spl_credits) = &spl_credits {
+        spl_credits.mint_to_player(player, credits_out as u64)?;
+    }
+    
     // Add credits to player account
-    player.credits += credits_out as u64;
+    if spl_credits.is_none() {
+        player.credits += credits_out as u64;
+    }
     
     emit!(SwapExecuted {
         user: ctx.accounts.user.key(),
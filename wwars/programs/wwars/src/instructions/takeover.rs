@@ -1,8 +1,10 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use anchor_spl::token::{Mint, Token, TokenAccount};
 use crate::state::*;
 use crate::math::*;
 use crate::events::*;
+use crate::instructions::wealth_escrow::{pay_wealth, WealthSource};
+use crate::instructions::open_achievements::unlock_achievement;
 use crate::errors::ErrorCode;
 
 #[derive(Accounts)]
@@ -13,17 +15,19 @@ pub struct Takeover<'info> {
 
     #[account(
         mut,
-        seeds = [b"player", attacker.key().as_ref()],
-        bump = attacker_player.bump,
-        constraint = attacker_player.authority == attacker.key() @ ErrorCode::Unauthorized
+        seeds = [b"player_state", attacker.key().as_ref()],
+        bump = attacker_state.bump,
+        constraint = attacker_state.owner == attacker.key() @ ErrorCode::Unauthorized,
+        constraint = attacker_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
-    pub attacker_player: Account<'info, Player>,
+    pub attacker_state: Account<'info, PlayerState>,
 
     /// CHECK: Target player account
     pub target_player: AccountInfo<'info>,
 
     #[account(
-        constraint = !game_config.paused @ ErrorCode::GamePaused
+        mut,
+        constraint = !game_config.is_paused(PAUSE_TAKEOVER) @ ErrorCode::GamePaused
     )]
     pub game_config: Account<'info, GameConfig>,
 
@@ -40,12 +44,28 @@ pub struct Takeover<'info> {
     )]
     pub target_holding: Account<'info, Holding>,
 
+    /// The holding's current owner, required unless it is tokenized
+    #[account(
+        mut,
+        seeds = [b"player_state", target_holding.player.as_ref()],
+        bump = target_state.bump
+    )]
+    pub target_state: Option<Account<'info, PlayerState>>,
+
     #[account(
         mut,
         token::mint = game_config.wealth_mint,
         token::authority = attacker
     )]
-    pub attacker_token_account: Account<'info, TokenAccount>,
+    pub attacker_token_account: Option<Account<'info, TokenAccount>>,
+
+    /// Escrowed WEALTH pays when the token account above is omitted
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Option<Account<'info, TokenAccount>>,
 
     #[account(
         mut,
@@ -56,7 +76,37 @@ pub struct Takeover<'info> {
     )]
     pub treasury_vault: Account<'info, TokenAccount>,
 
+    #[account(
+        mut,
+        address = game_config.wealth_mint
+    )]
+    pub wealth_mint: Account<'info, Mint>,
+
     pub token_program: Program<'info, Token>,
+
+    /// Current season stats; recorded into while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_stats".as_ref(), &season_stats.season_id.to_le_bytes(), attacker.key().as_ref()],
+        bump = season_stats.bump
+    )]
+    pub season_stats: Option<Account<'info, PlayerSeasonStats>>,
+
+    /// Current season pass; earns XP while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &season_pass.season_id.to_le_bytes(), attacker.key().as_ref()],
+        bump = season_pass.bump
+    )]
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+
+    /// Achievements to unlock milestones into
+    #[account(
+        mut,
+        seeds = [b"achievements", attacker.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
 }
 
 pub fn takeover(ctx: Context<Takeover>, _target_player: Pubkey, _class_id: u64) -> Result<()> {
@@ -86,25 +136,84 @@ pub fn takeover(ctx: Context<Takeover>, _target_player: Pubkey, _class_id: u64)
         .ok_or(ErrorCode::MathOverflow)?;
     let total_cost = cost.checked_add(fee as u64).ok_or(ErrorCode::MathOverflow)?;
 
-    // Transfer tokens
-    let cpi_accounts = Transfer {
-        from: ctx.accounts.attacker_token_account.to_account_info(),
-        to: ctx.accounts.treasury_vault.to_account_info(),
-        authority: ctx.accounts.attacker.to_account_info(),
-    };
-    let cpi_program = ctx.accounts.token_program.to_account_info();
-    let cpi_ctx = CpiContext::new(cpi_program, cpi_accounts);
-    token::transfer(cpi_ctx, total_cost)?;
+    // Split the spend between the treasury vault and a burn
+    let burn_amount = bps_of(total_cost, game_config.burn_bps)?;
+    let vault_amount = total_cost.checked_sub(burn_amount).ok_or(ErrorCode::MathOverflow)?;
+
+    // Pay from the token account, or the escrow when it is omitted
+    let source = WealthSource::resolve(
+        ctx.accounts.attacker_token_account.as_ref(),
+        ctx.accounts.attacker.to_account_info(),
+        &[],
+        ctx.accounts.wealth_escrow.as_ref(),
+        &ctx.accounts.game_config,
+        &mut ctx.accounts.attacker_state,
+        total_cost,
+    )?;
+    pay_wealth(
+        &ctx.accounts.token_program,
+        &ctx.accounts.wealth_mint,
+        &ctx.accounts.treasury_vault,
+        source,
+        vault_amount,
+        burn_amount,
+    )?;
+
+    if let Some(stats) = ctx.accounts.season_stats.as_deref_mut() {
+        if stats.is_live(clock.unix_timestamp) {
+            stats.takeovers = stats.takeovers.saturating_add(1);
+        }
+    }
+    if let Some(pass) = ctx.accounts.season_pass.as_deref_mut() {
+        pass.add_xp(XP_TAKEOVER, clock.unix_timestamp);
+    }
+    if let Some(achievements) = ctx.accounts.achievements.as_deref_mut() {
+        unlock_achievement(achievements, &mut ctx.accounts.attacker_state, ACH_FIRST_TAKEOVER, clock.unix_timestamp)?;
+    }
+
+    // Only untokenized holdings are tracked in `holdings_count`, and the
+    // holding is untokenized once the attacker owns it
+    if !target_holding.is_tokenized() {
+        let target_state = ctx.accounts.target_state.as_deref_mut().ok_or(ErrorCode::InvalidParameters)?;
+        target_state.holdings_count = target_state.holdings_count.saturating_sub(1);
+    }
+    let attacker_state = &mut ctx.accounts.attacker_state;
+    attacker_state.holdings_count = attacker_state.holdings_count.saturating_add(1);
 
     // Transfer ownership
     let from_player = target_holding.player;
     target_holding.player = ctx.accounts.attacker.key();
+    target_holding.holder_since = clock.unix_timestamp;
     target_holding.shield = 0;
     target_holding.upgrade_end_ts = 0;
     target_holding.last_defend_ts = clock.unix_timestamp;
     target_holding.last_risk_ts = clock.unix_timestamp;
     target_holding.risk_score = game_config.risk_threshold / 4; // Reset to low baseline
 
+    // The old NFT no longer controls the holding; it can be tokenized again
+    if target_holding.is_tokenized() {
+        emit!(HoldingNftDetached {
+            holding: target_holding.key(),
+            mint: target_holding.mint,
+            timestamp: clock.unix_timestamp,
+        });
+        target_holding.mint = Pubkey::default();
+        target_holding.mint_nonce = target_holding.mint_nonce.wrapping_add(1);
+    }
+
+    if burn_amount > 0 {
+        let game_config = &mut ctx.accounts.game_config;
+        game_config.total_burned = game_config.total_burned.saturating_add(burn_amount);
+
+        emit!(TokensBurned {
+            player: ctx.accounts.attacker.key(),
+            action: SpendAction::Takeover,
+            amount: burn_amount,
+            total_burned: game_config.total_burned,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+
     emit!(TakenOver {
         from_player,
         to_player: target_holding.player,
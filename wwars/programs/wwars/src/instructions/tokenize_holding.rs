@@ -0,0 +1,125 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::*;
+use crate::events::*;
+use crate::errors::ErrorCode;
+use crate::instructions::game_nft::mint_game_nft;
+
+pub const HOLDING_NFT_NAME: &str = "Wealth Wars Holding";
+pub const HOLDING_NFT_SYMBOL: &str = "WWHOLD";
+
+#[derive(Accounts)]
+#[instruction(class_id: u64)]
+pub struct TokenizeHolding<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        seeds = [b"class", &class_id.to_le_bytes()],
+        bump = asset_class.bump
+    )]
+    pub asset_class: Account<'info, AssetClass>,
+
+    #[account(
+        seeds = [b"business_nft_config"],
+        bump = nft_config.bump
+    )]
+    pub nft_config: Account<'info, BusinessNftConfig>,
+
+    #[account(
+        mut,
+        constraint = holding.class == asset_class.key() @ ErrorCode::InvalidParameters,
+        constraint = holding.player == owner.key() @ ErrorCode::Unauthorized,
+        constraint = !holding.is_tokenized() @ ErrorCode::AlreadyTokenized
+    )]
+    pub holding: Account<'info, Holding>,
+
+    #[account(
+        init,
+        payer = owner,
+        seeds = [b"holding_mint", holding.key().as_ref(), &[holding.mint_nonce]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = game_config,
+        mint::token_program = token_program
+    )]
+    pub holding_mint: InterfaceAccount<'info, Mint>,
+
+    /// CHECK: Metaplex metadata PDA of the mint, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), holding_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub holding_metadata: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = holding_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_nft_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mints a single NFT for the holding to its owner, in the same Token-2022
+/// and Metaplex metadata format as business NFTs. From then on `defend`,
+/// `queue_upgrade`, `finish_upgrade` and `claim_holding_yield` authorize
+/// whoever holds the token.
+pub fn tokenize_holding(ctx: Context<TokenizeHolding>, class_id: u64) -> Result<()> {
+    let clock = Clock::get()?;
+
+    mint_game_nft(
+        &ctx.accounts.game_config,
+        &ctx.accounts.holding_mint,
+        &ctx.accounts.holding_metadata,
+        &ctx.accounts.owner_nft_account,
+        &ctx.accounts.owner,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_metadata_program,
+        &ctx.accounts.system_program,
+        format!("{} #{}", HOLDING_NFT_NAME, class_id),
+        HOLDING_NFT_SYMBOL.to_string(),
+        format!("{}holding-{}.json", ctx.accounts.nft_config.base_uri, class_id),
+    )?;
+
+    let holding = &mut ctx.accounts.holding;
+    holding.mint = ctx.accounts.holding_mint.key();
+
+    // Tokenized holdings follow the NFT, so they leave `holdings_count`
+    let player_state = &mut ctx.accounts.player_state;
+    player_state.holdings_count = player_state.holdings_count.saturating_sub(1);
+
+    emit!(HoldingTokenized {
+        player: holding.player,
+        holding: holding.key(),
+        class: holding.class,
+        mint: holding.mint,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::math::bps_of;
+use crate::events::CreditsTransferred;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+#[instruction(to: Pubkey)]
+pub struct TransferCredits<'info> {
+    pub sender: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", sender.key().as_ref()],
+        bump = sender_state.bump,
+        constraint = sender_state.owner == sender.key() @ ErrorCode::Unauthorized,
+        constraint = sender_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub sender_state: Account<'info, PlayerState>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", to.as_ref()],
+        bump = recipient_state.bump,
+        constraint = to != sender.key() @ ErrorCode::InvalidParameters,
+        constraint = recipient_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub recipient_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_CREDIT_TRANSFER) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+}
+
+/// Sends `amount` credits to another player. The sender also pays the fee,
+/// which is burned, and both count toward the sender's daily limit.
+pub fn transfer_credits(ctx: Context<TransferCredits>, to: Pubkey, amount: u64) -> Result<()> {
+    let config = &ctx.accounts.game_config;
+    let sender = &mut ctx.accounts.sender_state;
+    let recipient = &mut ctx.accounts.recipient_state;
+    let clock = Clock::get()?;
+
+    require!(amount > 0, ErrorCode::ZeroAmount);
+
+    let fee = bps_of(amount, config.credit_transfer_fee_bps)?;
+    let total = amount.checked_add(fee).ok_or(ErrorCode::MathOverflow)?;
+    require!(sender.credits >= total, ErrorCode::InsufficientCredits);
+    let sent_today = sender.count_credits_sent(config, total, clock.unix_timestamp)?;
+
+    sender.credits -= total;
+    recipient.credits = recipient.credits.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(CreditsTransferred {
+        from: sender.owner,
+        to,
+        amount,
+        fee,
+        sent_today,
+        timestamp: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
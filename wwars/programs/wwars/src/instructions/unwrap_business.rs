@@ -0,0 +1,141 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{self, Burn, CloseAccount, Mint, TokenAccount};
+use crate::state::{BusinessNft, BusinessType, GameConfig, PlayerState, WorkConfig, PAUSE_BUSINESS};
+use crate::errors::ErrorCode;
+use crate::events::{BusinessNftIncomeClaimed, BusinessUnwrapped};
+use crate::instructions::purchase_business::add_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+use crate::instructions::claim_business_nft_income::settle_business_nft_income;
+
+#[derive(Accounts)]
+pub struct UnwrapBusiness<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", holder.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub holder: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"business_nft", business_mint.key().as_ref()],
+        bump = business_nft.bump,
+        constraint = business_nft.mint == business_mint.key() @ ErrorCode::InvalidParameters,
+        close = holder
+    )]
+    pub business_nft: Account<'info, BusinessNft>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_nft.business_id]],
+        bump = business_type.bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+    
+    #[account(mut, mint::token_program = token_program)]
+    pub business_mint: InterfaceAccount<'info, Mint>,
+    
+    // Ownership is whoever holds the token, wherever it was traded
+    #[account(
+        mut,
+        token::mint = business_mint,
+        token::authority = holder,
+        token::token_program = token_program,
+        constraint = holder_nft_account.amount == 1 @ ErrorCode::NotOwned
+    )]
+    pub holder_nft_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token2022>,
+}
+
+/// Burns a business NFT and adds the business, with its level, to the
+/// holder's `PlayerState`. The mint and token account are closed to the
+/// holder, along with any income the NFT accrued. `remaining_accounts` must
+/// hold the `BusinessType` account of every owned business, in ownership
+/// order, so pending income is settled first.
+pub fn unwrap_business<'info>(
+    ctx: Context<'_, '_, 'info, 'info, UnwrapBusiness<'info>>,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let record = &mut ctx.accounts.business_nft;
+    let clock = Clock::get()?;
+    
+    require!(!player.businesses_owned.contains(&record.business_id), ErrorCode::AlreadyOwned);
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    let (nft_income, accrued_secs) =
+        settle_business_nft_income(player, record, &ctx.accounts.business_type, clock.unix_timestamp)?;
+    if nft_income > 0 {
+        emit!(BusinessNftIncomeClaimed {
+            player: player.owner,
+            mint: record.mint,
+            business_id: record.business_id,
+            amount: nft_income,
+            accrued_secs,
+            timestamp: clock.unix_timestamp,
+        });
+    }
+    
+    let burn_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        Burn {
+            mint: ctx.accounts.business_mint.to_account_info(),
+            from: ctx.accounts.holder_nft_account.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token_interface::burn(burn_ctx, 1)?;
+    
+    let close_ctx = CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.holder_nft_account.to_account_info(),
+            destination: ctx.accounts.holder.to_account_info(),
+            authority: ctx.accounts.holder.to_account_info(),
+        },
+    );
+    token_interface::close_account(close_ctx)?;
+    
+    let config_seeds = &[b"config".as_ref(), &[ctx.accounts.game_config.bump_config]];
+    let signer = &[&config_seeds[..]];
+    let close_ctx = CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.business_mint.to_account_info(),
+            destination: ctx.accounts.holder.to_account_info(),
+            authority: ctx.accounts.game_config.to_account_info(),
+        },
+        signer,
+    );
+    token_interface::close_account(close_ctx)?;
+    
+    add_business(player, &ctx.accounts.work_config, record.business_id, record.level);
+    
+    emit!(BusinessUnwrapped {
+        player: player.owner,
+        business_id: record.business_id,
+        level: record.level,
+        mint: record.mint,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
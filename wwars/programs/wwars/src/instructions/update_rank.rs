@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, TokenAccount};
+use anchor_spl::token_interface;
+use crate::state::*;
+use crate::math::{authorize_business_nft, authorize_holding, price_for_level};
+use crate::events::RankUpdated;
+use crate::errors::ErrorCode;
+use crate::instructions::purchase_business::load_business_types;
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct UpdateRank<'info> {
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", player.as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"leaderboard".as_ref(), &game_config.current_season.to_le_bytes()],
+        bump = leaderboard.bump
+    )]
+    pub leaderboard: Account<'info, Leaderboard>,
+
+    /// CHECK: The player's WEALTH associated token account; counts as zero
+    /// when it does not exist
+    #[account(address = get_associated_token_address(&player, &game_config.wealth_mint))]
+    pub player_wealth_account: UncheckedAccount<'info>,
+}
+
+/// Permissionless crank that recomputes `player`'s net worth and updates the
+/// current season's leaderboard.
+///
+/// Net worth is credits, escrowed and wallet WEALTH, the catalog cost of every
+/// owned business and each holding at `price_for_level`. `remaining_accounts`
+/// must hold the `BusinessType` of every owned business in ownership order,
+/// followed by `(Holding, AssetClass)` pairs for the player's holdings. A
+/// tokenized holding counts for whoever holds its NFT, so its pair is followed
+/// by that holder's token account. Wrapped businesses the player holds count
+/// at catalog cost too, each passed as `(BusinessNft, BusinessType, token
+/// account)` among the holdings.
+///
+/// At least `holdings_count` untokenized holdings must be passed, so nobody
+/// can leave out a tracked holding. Tokenized ones change hands outside the
+/// program and only count when passed. When the player cranks themselves, the
+/// count is reset to what they proved, picking up holdings bought before it
+/// was tracked.
+///
+/// A holding or business NFT that changed hands only counts for its new
+/// holder once `LEADERBOARD_STALE_SECS` have passed since, when no fresh entry
+/// of the previous holder can still include it. Passing one whose recorded
+/// holder is out of date moves it to `player`, so it must then be writable.
+///
+/// Anyone else may only raise a fresh entry or confirm it at the same value;
+/// only the player can lower their own.
+pub fn update_rank<'info>(ctx: Context<'_, '_, 'info, 'info, UpdateRank<'info>>, player: Pubkey) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let player_state = &mut ctx.accounts.player_state;
+    let owned = player_state.businesses_owned.len();
+    require!(ctx.remaining_accounts.len() >= owned, ErrorCode::BusinessTypeMismatch);
+    let (business_accounts, holding_accounts) = ctx.remaining_accounts.split_at(owned);
+
+    let mut net_worth = player_state.credits as u128 + player_state.wealth_tokens as u128;
+
+    let wallet = &ctx.accounts.player_wealth_account;
+    if wallet.owner == &token::ID && !wallet.data_is_empty() {
+        let account = TokenAccount::try_deserialize(&mut &wallet.data.borrow()[..])?;
+        net_worth += account.amount as u128;
+    }
+
+    for business_type in load_business_types(&player_state.businesses_owned, business_accounts)? {
+        net_worth += business_type.cost as u128;
+    }
+
+    let mut counted: Vec<Pubkey> = Vec::new();
+    let mut untokenized: u16 = 0;
+    let mut accounts = holding_accounts.iter();
+    while let Some(info) = accounts.next() {
+        if info.try_borrow_data()?.starts_with(BusinessNft::DISCRIMINATOR) {
+            let mut record = Account::<BusinessNft>::try_from(info)?;
+            let business_type = Account::<BusinessType>::try_from(accounts.next().ok_or(ErrorCode::InvalidParameters)?)?;
+            let nft_account = InterfaceAccount::<token_interface::TokenAccount>::try_from(
+                accounts.next().ok_or(ErrorCode::InvalidParameters)?,
+            )?;
+            require!(business_type.business_id == record.business_id, ErrorCode::BusinessTypeMismatch);
+            require!(!counted.contains(&record.key()), ErrorCode::InvalidParameters);
+            counted.push(record.key());
+            authorize_business_nft(&record, &player, Some(&nft_account))?;
+
+            if record.holder != player {
+                record.holder = player;
+                record.holder_since = now;
+                record.exit(&crate::ID)?;
+            }
+            if settled_holder(record.holder_since, now) {
+                net_worth += business_type.cost as u128;
+            }
+            continue;
+        }
+
+        let mut holding = Account::<Holding>::try_from(info)?;
+        let asset_class = Account::<AssetClass>::try_from(accounts.next().ok_or(ErrorCode::InvalidParameters)?)?;
+        require!(holding.class == asset_class.key(), ErrorCode::InvalidParameters);
+        require!(!counted.contains(&holding.key()), ErrorCode::InvalidParameters);
+        counted.push(holding.key());
+
+        let nft_account = if holding.is_tokenized() {
+            let info = accounts.next().ok_or(ErrorCode::InvalidParameters)?;
+            Some(InterfaceAccount::<token_interface::TokenAccount>::try_from(info)?)
+        } else {
+            untokenized = untokenized.saturating_add(1);
+            None
+        };
+        let previous_holder = holding.player;
+        authorize_holding(&mut holding, &player, nft_account.as_deref())?;
+        if holding.player != previous_holder {
+            holding.exit(&crate::ID)?;
+        }
+        if !settled_holder(holding.holder_since, now) {
+            continue;
+        }
+
+        let value = price_for_level(
+            asset_class.base_price,
+            asset_class.price_scale_num,
+            asset_class.price_scale_den,
+            holding.level,
+        )?;
+        net_worth += value as u128;
+    }
+
+    let self_crank = ctx.accounts.cranker.key() == player;
+    if self_crank {
+        player_state.holdings_count = untokenized;
+    } else {
+        require!(untokenized >= player_state.holdings_count, ErrorCode::HoldingsMissing);
+    }
+
+    let net_worth = u64::try_from(net_worth).unwrap_or(u64::MAX);
+    let leaderboard = &mut ctx.accounts.leaderboard;
+    if !self_crank {
+        // Leave the entry to age out rather than let a rival lower it
+        if let Some(entry) = leaderboard.fresh_entry(&player, now) {
+            if net_worth < entry.net_worth {
+                return Ok(());
+            }
+        }
+    }
+    let rank = leaderboard.record(player, net_worth, now);
+
+    emit!(RankUpdated {
+        season_id: leaderboard.season_id,
+        player,
+        net_worth,
+        rank,
+        timestamp: now,
+    });
+
+    Ok(())
+}
+
+/// Whether an asset has been with its holder long enough that no fresh
+/// leaderboard entry of a previous holder can still count it.
+fn settled_holder(holder_since: i64, now: i64) -> bool {
+    now.saturating_sub(holder_since) >= LEADERBOARD_STALE_SECS
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{BusinessType, GameConfig, PlayerState, MAX_BUSINESS_LEVEL, PAUSE_BUSINESS};
+use crate::errors::ErrorCode;
+use crate::events::BusinessUpgraded;
+use crate::instructions::purchase_business::business_upgrade_cost;
+
+#[derive(Accounts)]
+#[instruction(business_id: u8)]
+pub struct UpgradeBusiness<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"business".as_ref(), &[business_id]],
+        bump = business_type.bump
+    )]
+    pub business_type: Account<'info, BusinessType>,
+}
+
+pub fn upgrade_business(ctx: Context<UpgradeBusiness>, business_id: u8) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let business_type = &ctx.accounts.business_type;
+    let clock = Clock::get()?;
+    
+    require!(player.businesses_owned.contains(&business_id), ErrorCode::NotOwned);
+    
+    let level = player.business_levels[business_id as usize];
+    require!(level < MAX_BUSINESS_LEVEL, ErrorCode::MaxLevelReached);
+    
+    // Escalating cost based on the catalog price
+    let cost = business_upgrade_cost(business_type.cost, level)?;
+    require!(player.credits >= cost, ErrorCode::InsufficientCredits);
+    
+    player.credits -= cost;
+    player.business_levels[business_id as usize] = level + 1;
+    
+    emit!(BusinessUpgraded {
+        player: player.owner,
+        business_id,
+        new_level: level + 1,
+        cost,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
@@ -0,0 +1,256 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::events::{WealthDeposited, WealthWithdrawn};
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct InitializeWealthEscrow<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        has_one = admin @ ErrorCode::Unauthorized
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(address = game_config.wealth_mint)]
+    pub wealth_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = admin,
+        token::mint = wealth_mint,
+        token::authority = game_config,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump
+    )]
+    pub wealth_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_wealth_escrow(ctx: Context<InitializeWealthEscrow>) -> Result<()> {
+    ctx.accounts.game_config.bump_wealth_escrow = ctx.bumps.wealth_escrow;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositWealth<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_WEALTH_ESCROW) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Moves WEALTH from the owner's token account into the escrow vault and
+/// credits `PlayerState::wealth_tokens`.
+pub fn deposit_wealth(ctx: Context<DepositWealth>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::ZeroAmount);
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.owner_token_account.to_account_info(),
+        to: ctx.accounts.wealth_escrow.to_account_info(),
+        authority: ctx.accounts.owner.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new(cpi_program, cpi_accounts), amount)?;
+
+    let player = &mut ctx.accounts.player_state;
+    player.wealth_tokens = player.wealth_tokens.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    emit!(WealthDeposited {
+        player: player.owner,
+        amount,
+        escrowed: player.wealth_tokens,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawWealth<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_WEALTH_ESCROW) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        token::mint = game_config.wealth_mint,
+        token::authority = owner
+    )]
+    pub owner_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"wealth_escrow", game_config.key().as_ref()],
+        bump = game_config.bump_wealth_escrow
+    )]
+    pub wealth_escrow: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn withdraw_wealth(ctx: Context<WithdrawWealth>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::ZeroAmount);
+
+    let player = &mut ctx.accounts.player_state;
+    require!(player.wealth_tokens >= amount, ErrorCode::InsufficientFunds);
+    player.wealth_tokens -= amount;
+
+    let config_seeds = &[b"config".as_ref(), &[ctx.accounts.game_config.bump_config]];
+    let signer = &[&config_seeds[..]];
+
+    let cpi_accounts = Transfer {
+        from: ctx.accounts.wealth_escrow.to_account_info(),
+        to: ctx.accounts.owner_token_account.to_account_info(),
+        authority: ctx.accounts.game_config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::transfer(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+
+    emit!(WealthWithdrawn {
+        player: player.owner,
+        amount,
+        escrowed: player.wealth_tokens,
+        timestamp: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+/// Where a WEALTH spend is paid from.
+pub enum WealthSource<'a, 'info> {
+    /// The player's token account, signed by the owner or a session delegate
+    Wallet {
+        account: AccountInfo<'info>,
+        authority: AccountInfo<'info>,
+        signer: &'a [&'a [&'a [u8]]],
+    },
+    /// The shared escrow vault, already debited from `PlayerState::wealth_tokens`
+    Escrow {
+        account: AccountInfo<'info>,
+        game_config: AccountInfo<'info>,
+        bump_config: u8,
+    },
+}
+
+impl<'a, 'info> WealthSource<'a, 'info> {
+    /// Pays from `wallet` when passed, otherwise debits `amount` from the
+    /// player's escrowed balance.
+    pub fn resolve(
+        wallet: Option<&Account<'info, TokenAccount>>,
+        authority: AccountInfo<'info>,
+        signer: &'a [&'a [&'a [u8]]],
+        wealth_escrow: Option<&Account<'info, TokenAccount>>,
+        game_config: &Account<'info, GameConfig>,
+        player: &mut PlayerState,
+        amount: u64,
+    ) -> Result<Self> {
+        if let Some(wallet) = wallet {
+            return Ok(WealthSource::Wallet {
+                account: wallet.to_account_info(),
+                authority,
+                signer,
+            });
+        }
+
+        // Callers constrain `wealth_escrow` to `[b"wealth_escrow", config]`
+        let escrow = wealth_escrow.ok_or(ErrorCode::WealthSourceMissing)?;
+        require!(player.wealth_tokens >= amount, ErrorCode::InsufficientFunds);
+        player.wealth_tokens -= amount;
+
+        Ok(WealthSource::Escrow {
+            account: escrow.to_account_info(),
+            game_config: game_config.to_account_info(),
+            bump_config: game_config.bump_config,
+        })
+    }
+}
+
+/// Sends `vault_amount` to the treasury vault and burns `burn_amount`.
+pub fn pay_wealth<'info>(
+    token_program: &Program<'info, Token>,
+    wealth_mint: &Account<'info, Mint>,
+    treasury_vault: &Account<'info, TokenAccount>,
+    source: WealthSource<'_, 'info>,
+    vault_amount: u64,
+    burn_amount: u64,
+) -> Result<()> {
+    let (from, authority, bump_config, signer) = match source {
+        WealthSource::Wallet { account, authority, signer } => (account, authority, None, signer),
+        WealthSource::Escrow { account, game_config, bump_config } => {
+            (account, game_config, Some(bump_config), &[][..])
+        }
+    };
+    let config_bump = [bump_config.unwrap_or_default()];
+    let config_seeds = &[b"config".as_ref(), &config_bump];
+    let config_signer = &[&config_seeds[..]];
+    let signer: &[&[&[u8]]] = if bump_config.is_some() { config_signer } else { signer };
+
+    let cpi_accounts = Transfer {
+        from: from.clone(),
+        to: treasury_vault.to_account_info(),
+        authority: authority.clone(),
+    };
+    let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+    token::transfer(cpi_ctx, vault_amount)?;
+
+    if burn_amount > 0 {
+        let cpi_accounts = Burn {
+            mint: wealth_mint.to_account_info(),
+            from,
+            authority,
+        };
+        let cpi_ctx = CpiContext::new_with_signer(token_program.to_account_info(), cpi_accounts, signer);
+        token::burn(cpi_ctx, burn_amount)?;
+    }
+
+    Ok(())
+}
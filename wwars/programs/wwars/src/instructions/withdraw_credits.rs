@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::*;
+use crate::events::CreditsWithdrawn;
+use crate::errors::ErrorCode;
+
+#[derive(Accounts)]
+pub struct WithdrawCredits<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_CREDITS_BRIDGE) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump,
+        constraint = credits_mint_config.enabled @ ErrorCode::CreditsMintDisabled
+    )]
+    pub credits_mint_config: Account<'info, CreditsMintConfig>,
+
+    #[account(
+        mut,
+        address = credits_mint_config.mint
+    )]
+    pub credits_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = credits_mint,
+        token::authority = owner
+    )]
+    pub owner_credits_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// Moves credits out of `PlayerState::credits` and mints them as SPL tokens
+/// to the owner. Withdrawals are held to `transfer_credits`' account age and
+/// daily limit, and only the same player can deposit them back.
+pub fn withdraw_credits(ctx: Context<WithdrawCredits>, amount: u64) -> Result<()> {
+    require!(amount > 0, ErrorCode::ZeroAmount);
+    let now = Clock::get()?.unix_timestamp;
+
+    let player = &mut ctx.accounts.player_state;
+    require!(player.credits >= amount, ErrorCode::InsufficientCredits);
+    player.count_credits_sent(&ctx.accounts.game_config, amount, now)?;
+    player.credits -= amount;
+    player.bridged_credits = player.bridged_credits.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+
+    let config = &ctx.accounts.credits_mint_config;
+    let config_seeds = &[b"credits_mint_config".as_ref(), &[config.bump]];
+    let signer = &[&config_seeds[..]];
+
+    let cpi_accounts = MintTo {
+        mint: ctx.accounts.credits_mint.to_account_info(),
+        to: ctx.accounts.owner_credits_account.to_account_info(),
+        authority: config.to_account_info(),
+    };
+    let cpi_program = ctx.accounts.token_program.to_account_info();
+    token::mint_to(CpiContext::new_with_signer(cpi_program, cpi_accounts, signer), amount)?;
+
+    let config = &mut ctx.accounts.credits_mint_config;
+    config.total_withdrawn = config.total_withdrawn.saturating_add(amount);
+
+    emit!(CreditsWithdrawn {
+        player: player.owner,
+        amount,
+        credits: player.credits,
+        timestamp: now,
+    });
+
+    Ok(())
+}
@@ -1,28 +1,137 @@
 use anchor_lang::prelude::*;
-use crate::state::PlayerState;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use anchor_spl::token_interface;
+use crate::state::{Achievements, AttestationMode, BusinessNft, BusinessType, CreditsMintConfig, GameConfig, LevelDecayMode, PlayerSeasonStats, PlayerState, SeasonPass, SessionKey, WorkConfig, PAUSE_WORK, SESSION_DO_WORK, XP_DO_WORK};
+use crate::math::{authorize_business_nft, authorize_player, bps_of};
 use crate::errors::ErrorCode;
-use crate::events::{WorkCompleted, StreakBroken, LevelUp};
+use crate::events::{WorkCompleted, WorkRewardBreakdown, StreakBroken, LevelUp, ActiveBusinessesChanged};
+use crate::instructions::purchase_business::{load_business_types, trim_active_slots};
+use crate::instructions::open_achievements::unlock_progress_achievements;
+use crate::instructions::credits_token::SplCredits;
 
 #[derive(Accounts)]
 pub struct DoWork<'info> {
     #[account(
         mut,
         seeds = [b"player_state", owner.key().as_ref()],
-        bump = player_state.bump
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
     )]
     pub player_state: Account<'info, PlayerState>,
     
+    /// CHECK: Player wallet; signs itself or through `session`
+    pub owner: UncheckedAccount<'info>,
+    
     #[account(mut)]
-    pub owner: Signer<'info>,
+    pub signer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_WORK) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"work_config"],
+        bump = work_config.bump
+    )]
+    pub work_config: Account<'info, WorkConfig>,
+    
+    /// Must sign when `game_config.attestation_mode` is `CoSigner`
+    pub attestor: Option<Signer<'info>>,
+    
+    /// CHECK: Instructions sysvar, needed when the mode is `Ed25519`
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+    
+    #[account(
+        mut,
+        seeds = [b"session", owner.key().as_ref(), signer.key().as_ref()],
+        bump = session.bump
+    )]
+    pub session: Option<Account<'info, SessionKey>>,
+    
+    /// Current season stats; recorded into while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_stats".as_ref(), &season_stats.season_id.to_le_bytes(), owner.key().as_ref()],
+        bump = season_stats.bump
+    )]
+    pub season_stats: Option<Account<'info, PlayerSeasonStats>>,
+    
+    /// Current season pass; earns XP while the season is live
+    #[account(
+        mut,
+        seeds = [b"season_pass".as_ref(), &season_pass.season_id.to_le_bytes(), owner.key().as_ref()],
+        bump = season_pass.bump
+    )]
+    pub season_pass: Option<Account<'info, SeasonPass>>,
+    
+    /// Achievements to unlock milestones into
+    #[account(
+        mut,
+        seeds = [b"achievements", owner.key().as_ref()],
+        bump = achievements.bump
+    )]
+    pub achievements: Option<Account<'info, Achievements>>,
+    
+    /// Passing `owner_credits_account` mints the reward as SPL credits instead
+    /// of adding it to `PlayerState::credits`; the credits mint accounts and
+    /// token program must come with it
+    #[account(
+        seeds = [b"credits_mint_config"],
+        bump = credits_mint_config.bump
+    )]
+    pub credits_mint_config: Option<Account<'info, CreditsMintConfig>>,
+    
+    #[account(mut)]
+    pub credits_mint: Option<Account<'info, Mint>>,
+    
+    #[account(mut)]
+    pub owner_credits_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
 }
 
-pub fn do_work(ctx: Context<DoWork>) -> Result<()> {
-    let player = &mut ctx.accounts.player_state;
+// Active businesses of one set needed before its set bonus applies
+const SET_BONUS_MIN_ACTIVE: usize = 3;
+// Extra effect per business upgrade level, in percent
+const BUSINESS_LEVEL_BONUS_PCT: u64 = 10;
+
+/// `remaining_accounts` must hold the `BusinessType` account of every active
+/// business slot, in slot order, followed by the `BusinessNft` record and the
+/// owner's token account of each slot backed by a business NFT, also in slot
+/// order. A slot whose NFT has left the owner must be deactivated first.
+pub fn do_work<'info>(ctx: Context<'_, '_, 'info, 'info, DoWork<'info>>) -> Result<()> {
     let clock = Clock::get()?;
     let current_time = clock.unix_timestamp;
     
+    authorize_player(
+        &ctx.accounts.owner.key(),
+        &ctx.accounts.signer.key(),
+        ctx.accounts.session.as_deref_mut(),
+        SESSION_DO_WORK,
+        0,
+        0,
+        current_time,
+    )?;
+    
+    let spl_credits = SplCredits::resolve(
+        ctx.accounts.credits_mint_config.as_ref(),
+        ctx.accounts.credits_mint.as_ref(),
+        ctx.accounts.owner_credits_account.as_ref(),
+        ctx.accounts.token_program.as_ref(),
+        &ctx.accounts.owner.key(),
+    )?;
+    
+    let player = &mut ctx.accounts.player_state;
+    let work_config = &ctx.accounts.work_config;
+    
     // 1. Validate cooldown
-    let cooldown_seconds = (player.cooldown_hours as i64) * 3600;
+    let cooldown_seconds = (work_config.cooldown_hours(player.work_frequency_level) as i64) * 3600;
     let time_since_last_work = current_time - player.last_work_timestamp;
     
     require!(
@@ -30,32 +139,84 @@ pub fn do_work(ctx: Context<DoWork>) -> Result<()> {
         ErrorCode::CooldownActive
     );
     
+    verify_attestation(
+        &ctx.accounts.game_config,
+        ctx.accounts.attestor.as_ref(),
+        ctx.accounts.instructions_sysvar.as_ref(),
+        player,
+    )?;
+    
     // 2. Handle streak logic
     let old_streak = player.streak_count;
     let old_level = player.work_frequency_level;
-    handle_streak_update(player, current_time)?;
+    let streak = handle_streak_update(player, work_config, current_time)?;
+    
+    let slots = player.active_business_slots.len();
+    require!(ctx.remaining_accounts.len() >= slots, ErrorCode::BusinessTypeMismatch);
+    let (business_accounts, nft_accounts) = ctx.remaining_accounts.split_at(slots);
+    let mut business_types = load_business_types(&player.active_business_slots, business_accounts)?;
+    let mut business_levels = load_slot_levels(player, nft_accounts)?;
+    
+    // A streak break can drop the level below the active slot count
+    let trimmed = trim_active_slots(player, work_config);
+    business_types.truncate(player.active_business_slots.len());
+    business_levels.truncate(player.active_business_slots.len());
     
     // 3. Calculate rewards based on level and businesses
-    let base_reward = calculate_work_reward(player)?;
+    let breakdown = calculate_work_reward(player, work_config, &business_types, &business_levels)?;
+    let base_reward = breakdown.total()?;
     
     // 4. Update state
     player.last_work_timestamp = current_time;
     player.total_work_actions += 1;
-    player.credits += base_reward;
+    if let Some(spl_credits) = &spl_credits {
+        spl_credits.mint_to_player(player, base_reward)?;
+    } else {
+        player.credits += base_reward;
+    }
+    
+    if let Some(stats) = ctx.accounts.season_stats.as_deref_mut() {
+        if stats.is_live(current_time) {
+            stats.work_actions = stats.work_actions.saturating_add(1);
+            stats.credits_earned = stats.credits_earned.saturating_add(base_reward);
+        }
+    }
+    if let Some(pass) = ctx.accounts.season_pass.as_deref_mut() {
+        pass.add_xp(XP_DO_WORK, current_time);
+    }
+    if let Some(achievements) = ctx.accounts.achievements.as_deref_mut() {
+        unlock_progress_achievements(achievements, player, current_time)?;
+    }
     
     // 5. Emit events
     emit!(WorkCompleted {
         player: player.owner,
         reward: base_reward,
+        breakdown,
         new_streak: player.streak_count,
         new_level: player.work_frequency_level,
         timestamp: current_time,
     });
     
-    if player.streak_count < old_streak {
+    if streak.broken || streak.freezes_used > 0 {
         emit!(StreakBroken {
             player: player.owner,
             old_streak,
+            new_streak: player.streak_count,
+            old_level,
+            new_level: player.work_frequency_level,
+            freezes_used: streak.freezes_used,
+            freezes_remaining: player.streak_freezes,
+            timestamp: current_time,
+        });
+    }
+    
+    if !trimmed.is_empty() {
+        emit!(ActiveBusinessesChanged {
+            player: player.owner,
+            activated: None,
+            deactivated: trimmed,
+            active_business_slots: player.active_business_slots.clone(),
             timestamp: current_time,
         });
     }
@@ -72,95 +233,243 @@ pub fn do_work(ctx: Context<DoWork>) -> Result<()> {
     Ok(())
 }
 
-fn handle_streak_update(player: &mut PlayerState, current_time: i64) -> Result<()> {
-    const STREAK_WINDOW: i64 = 48 * 3600; // 48 hour window
+/// Checks the proof of presence required by the game config. Ed25519
+/// attestations sign `owner || total_work_actions` (u64 LE), so each one is
+/// good for a single work action.
+fn verify_attestation(
+    config: &GameConfig,
+    attestor: Option<&Signer>,
+    instructions_sysvar: Option<&UncheckedAccount>,
+    player: &PlayerState,
+) -> Result<()> {
+    match config.attestation_mode {
+        AttestationMode::None => Ok(()),
+        AttestationMode::CoSigner => {
+            let attestor = attestor.ok_or(ErrorCode::AttestationRequired)?;
+            require_keys_eq!(attestor.key(), config.attestation_authority, ErrorCode::AttestationRequired);
+            Ok(())
+        }
+        AttestationMode::Ed25519 => {
+            let sysvar = instructions_sysvar.ok_or(ErrorCode::AttestationRequired)?;
+            let mut message = player.owner.to_bytes().to_vec();
+            message.extend_from_slice(&player.total_work_actions.to_le_bytes());
+            
+            // Only instructions before this one can have been verified already
+            let current = load_current_index_checked(sysvar)?;
+            for index in 0..current {
+                let ix = load_instruction_at_checked(index as usize, sysvar)?;
+                if ix.program_id == ed25519_program::ID
+                    && ed25519_signs(&ix.data, &config.attestation_authority, &message)
+                {
+                    return Ok(());
+                }
+            }
+            err!(ErrorCode::AttestationRequired)
+        }
+    }
+}
+
+/// Whether an Ed25519 program instruction verifies exactly one signature by
+/// `signer` over `message`, with key and message inside its own data.
+fn ed25519_signs(data: &[u8], signer: &Pubkey, message: &[u8]) -> bool {
+    const OFFSETS_START: usize = 2;
+    const OFFSETS_LEN: usize = 14;
+    const THIS_INSTRUCTION: usize = u16::MAX as usize;
+    
+    if data.len() < OFFSETS_START + OFFSETS_LEN || data[0] != 1 {
+        return false;
+    }
+    let read_u16 = |at: usize| u16::from_le_bytes([data[OFFSETS_START + at], data[OFFSETS_START + at + 1]]) as usize;
+    
+    let signature_ix = read_u16(2);
+    let pubkey_offset = read_u16(4);
+    let pubkey_ix = read_u16(6);
+    let message_offset = read_u16(8);
+    let message_size = read_u16(10);
+    let message_ix = read_u16(12);
+    
+    signature_ix == THIS_INSTRUCTION
+        && pubkey_ix == THIS_INSTRUCTION
+        && message_ix == THIS_INSTRUCTION
+        && data.get(pubkey_offset..pubkey_offset + 32) == Some(signer.as_ref())
+        && data.get(message_offset..message_offset + message_size) == Some(message)
+}
+
+// Missed windows applied to the activity score when a streak breaks
+const MAX_ACTIVITY_DECAY_WINDOWS: i64 = 64;
+
+struct StreakOutcome {
+    broken: bool,
+    freezes_used: u8,
+}
+
+fn handle_streak_update(player: &mut PlayerState, work_config: &WorkConfig, current_time: i64) -> Result<StreakOutcome> {
     const DAY_SECONDS: i64 = 24 * 3600;
     
     let time_since_last = current_time - player.last_work_timestamp;
+    let streak_window = work_config.tables.streak_window_secs;
+    let mut outcome = StreakOutcome { broken: false, freezes_used: 0 };
     
     if player.last_work_timestamp == 0 {
         // First time working
         player.streak_count = 1;
-    } else if time_since_last <= STREAK_WINDOW {
+        record_activity(player, work_config)?;
+    } else if time_since_last <= streak_window {
         // Within streak window
         if time_since_last >= DAY_SECONDS {
             // Valid next day work
             player.streak_count += 1;
+            record_activity(player, work_config)?;
             
             // Level up check
-            update_work_frequency_level(player)?;
+            update_work_frequency_level(player, work_config)?;
         }
         // Same day work doesn't break streak but doesn't increment
     } else {
-        // Streak broken
-        player.streak_count = 1;
-        player.work_frequency_level = 0; // Reset to novice
-        player.cooldown_hours = 24;
+        // Each freeze bridges one more missed window
+        let missed_windows = (time_since_last - streak_window + streak_window - 1) / streak_window;
+        
+        if missed_windows <= player.streak_freezes as i64 {
+            outcome.freezes_used = missed_windows as u8;
+            player.streak_freezes -= outcome.freezes_used;
+            player.streak_count += 1;
+            record_activity(player, work_config)?;
+            update_work_frequency_level(player, work_config)?;
+        } else {
+            // Streak broken
+            outcome.broken = true;
+            for _ in 0..missed_windows.min(MAX_ACTIVITY_DECAY_WINDOWS) {
+                decay_activity(player, work_config)?;
+            }
+            
+            let new_level = decayed_level(player, work_config, missed_windows);
+            player.work_frequency_level = new_level;
+            player.cooldown_hours = work_config.cooldown_hours(new_level);
+            // Resume progress from the start of the level kept
+            player.streak_count = work_config.tables.streak_thresholds[new_level as usize].max(1);
+            record_activity(player, work_config)?;
+        }
     }
     
     player.last_streak_check = current_time;
+    Ok(outcome)
+}
+
+/// Level a player keeps after missing `missed_windows` streak windows.
+fn decayed_level(player: &PlayerState, work_config: &WorkConfig, missed_windows: i64) -> u8 {
+    let level = player.work_frequency_level;
+    match work_config.level_decay_mode {
+        LevelDecayMode::Reset => 0,
+        LevelDecayMode::OneTierPerWindow => {
+            level.saturating_sub(missed_windows.min(u8::MAX as i64) as u8)
+        }
+        LevelDecayMode::ActivityScore => {
+            let activity_days = player.activity_score / 100;
+            work_config.level_for_streak(activity_days).min(level)
+        }
+    }
+}
+
+/// Counts a work day toward the activity score after decaying it once.
+fn record_activity(player: &mut PlayerState, work_config: &WorkConfig) -> Result<()> {
+    decay_activity(player, work_config)?;
+    player.activity_score = player.activity_score.saturating_add(100);
     Ok(())
 }
 
-fn update_work_frequency_level(player: &mut PlayerState) -> Result<()> {
-    // Level progression: 1 day, 3 days, 7 days, 14 days, 30 days
-    let new_level = match player.streak_count {
-        1..=2 => 0,     // Novice: 24h cooldown
-        3..=6 => 1,     // Regular: 18h cooldown  
-        7..=13 => 2,    // Experienced: 12h cooldown
-        14..=29 => 3,   // Expert: 9h cooldown
-        30.. => 4,      // Master: 6h cooldown
-        _ => 0,
-    };
+fn decay_activity(player: &mut PlayerState, work_config: &WorkConfig) -> Result<()> {
+    let decay = bps_of(player.activity_score as u64, work_config.activity_decay_bps)?;
+    player.activity_score -= decay as u32;
+    Ok(())
+}
+
+fn update_work_frequency_level(player: &mut PlayerState, work_config: &WorkConfig) -> Result<()> {
+    let new_level = work_config.level_for_streak(player.streak_count);
     
     if new_level > player.work_frequency_level {
         player.work_frequency_level = new_level;
-        
-        // Update cooldown based on level
-        player.cooldown_hours = match new_level {
-            0 => 24,  // Novice
-            1 => 18,  // Regular
-            2 => 12,  // Experienced
-            3 => 9,   // Expert
-            4 => 6,   // Master
-            _ => 24,
-        };
+        player.cooldown_hours = work_config.cooldown_hours(new_level);
     }
     
     Ok(())
 }
 
-fn calculate_work_reward(player: &PlayerState) -> Result<u64> {
-    // Base reward starts at 100 credits
-    let mut base_reward = 100u64;
+/// Upgrade level of each active slot, read from the business NFT record for
+/// slots it backs. `accounts` holds a `(BusinessNft, token account)` pair per
+/// NFT-backed slot, in slot order.
+fn load_slot_levels<'info>(player: &PlayerState, accounts: &'info [AccountInfo<'info>]) -> Result<Vec<u8>> {
+    let mut accounts = accounts.iter();
+    let levels = player
+        .active_business_slots
+        .iter()
+        .map(|business_id| {
+            if !player.is_nft_slot(*business_id) {
+                return Ok(player.business_levels[*business_id as usize]);
+            }
+            let record = Account::<BusinessNft>::try_from(accounts.next().ok_or(ErrorCode::NotOwned)?)?;
+            let nft_account = InterfaceAccount::<token_interface::TokenAccount>::try_from(
+                accounts.next().ok_or(ErrorCode::NotOwned)?,
+            )?;
+            require!(record.business_id == *business_id, ErrorCode::BusinessTypeMismatch);
+            authorize_business_nft(&record, &player.owner, Some(&nft_account))?;
+            Ok(record.level)
+        })
+        .collect::<Result<Vec<u8>>>()?;
+    require!(accounts.next().is_none(), ErrorCode::BusinessTypeMismatch);
+    Ok(levels)
+}
+
+fn calculate_work_reward(
+    player: &PlayerState,
+    work_config: &WorkConfig,
+    business_types: &[BusinessType],
+    business_levels: &[u8],
+) -> Result<WorkRewardBreakdown> {
+    let tables = &work_config.tables;
+    let base = tables.base_reward;
     
     // Bonus based on work frequency level
-    let level_multiplier = match player.work_frequency_level {
-        0 => 100,   // 1.0x
-        1 => 125,   // 1.25x
-        2 => 150,   // 1.5x
-        3 => 175,   // 1.75x
-        4 => 200,   // 2.0x
-        _ => 100,
-    };
-    
-    base_reward = base_reward * level_multiplier / 100;
-    
-    // Business synergy bonus (calculated from active slots)
-    let slot_count = player.active_business_slots.len() as u64;
-    let synergy_multiplier = match slot_count {
-        0..=1 => 100,   // 1.0x
-        2 => 125,       // 1.25x
-        3 => 150,       // 1.5x
-        4 => 175,       // 1.75x
-        5.. => 200,     // 2.0x
-    };
-    
-    base_reward = base_reward * synergy_multiplier / 100;
+    let level_multiplier = work_config.reward_multiplier_pct(player.work_frequency_level) as u64;
+    
+    let leveled: u64 = (base as u128 * level_multiplier as u128 / 100)
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    
+    // Business multipliers: each active business adds its own bps on top of
+    // the leveled reward, plus its set bonus when enough of its set is active
+    let mut multiplier_bps = 0u64;
+    let mut set_bonus_bps = 0u64;
+    let mut flat_bonus = 0u64;
+    for (business, level) in business_types.iter().zip(business_levels) {
+        // Each upgrade level adds 10% to the business's own effects
+        let level = *level as u64;
+        let level_pct = 100 + level * BUSINESS_LEVEL_BONUS_PCT;
+        
+        multiplier_bps += business.work_multiplier_bps as u64 * level_pct / 100;
+        let business_flat = (business.flat_bonus as u128 * level_pct as u128 / 100) as u64;
+        flat_bonus = flat_bonus.checked_add(business_flat).ok_or(ErrorCode::MathOverflow)?;
+        
+        if business.set_id != 0 {
+            let set_active = business_types
+                .iter()
+                .filter(|b| b.set_id == business.set_id)
+                .count();
+            if set_active >= SET_BONUS_MIN_ACTIVE {
+                set_bonus_bps += business.set_bonus_bps as u64;
+            }
+        }
+    }
     
     // Streak bonus (small bonus for consistency)
-    let streak_bonus = (player.streak_count as u64).min(50) * 2; // Max 100 bonus
-    base_reward += streak_bonus;
+    let streak_days = player.streak_count.min(tables.streak_bonus_cap_days) as u64;
+    let streak_bonus = streak_days.checked_mul(tables.streak_bonus_per_day).ok_or(ErrorCode::MathOverflow)?;
     
-    Ok(base_reward)
+    Ok(WorkRewardBreakdown {
+        base,
+        level_bonus: leveled.saturating_sub(base),
+        business_multiplier_bonus: leveled * multiplier_bps / 10_000,
+        set_bonus: leveled * set_bonus_bps / 10_000,
+        business_flat_bonus: flat_bonus,
+        streak_bonus,
+    })
 }
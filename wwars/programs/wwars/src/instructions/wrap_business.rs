@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::metadata::Metadata;
+use anchor_spl::token_2022::Token2022;
+use anchor_spl::token_interface::{Mint, TokenAccount};
+use crate::state::{BusinessNft, BusinessNftConfig, GameConfig, PlayerState, PAUSE_BUSINESS};
+use crate::migration::Versioned;
+use crate::errors::ErrorCode;
+use crate::events::BusinessWrapped;
+use crate::instructions::purchase_business::remove_business;
+use crate::instructions::claim_business_income::settle_and_emit_income;
+use crate::instructions::game_nft::mint_game_nft;
+
+pub const BUSINESS_NFT_NAME: &str = "Wealth Wars Business";
+pub const BUSINESS_NFT_SYMBOL: &str = "WWBIZ";
+
+#[derive(Accounts)]
+pub struct WrapBusiness<'info> {
+    #[account(
+        mut,
+        seeds = [b"player_state", owner.key().as_ref()],
+        bump = player_state.bump,
+        constraint = player_state.in_season(game_config.current_season) @ ErrorCode::SeasonCarryOverPending
+    )]
+    pub player_state: Account<'info, PlayerState>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    #[account(
+        seeds = [b"config"],
+        bump = game_config.bump_config,
+        constraint = !game_config.is_paused(PAUSE_BUSINESS) @ ErrorCode::GamePaused
+    )]
+    pub game_config: Account<'info, GameConfig>,
+    
+    #[account(
+        seeds = [b"business_nft_config"],
+        bump = nft_config.bump,
+        constraint = nft_config.enabled @ ErrorCode::BusinessNftDisabled
+    )]
+    pub nft_config: Account<'info, BusinessNftConfig>,
+    
+    /// Fresh keypair; the game config PDA is close authority, and mint
+    /// authority until the single token is minted
+    #[account(
+        init,
+        payer = owner,
+        mint::decimals = 0,
+        mint::authority = game_config,
+        mint::token_program = token_program,
+        extensions::close_authority::authority = game_config
+    )]
+    pub business_mint: InterfaceAccount<'info, Mint>,
+    
+    /// CHECK: Metaplex metadata PDA of the mint, created by the metadata program
+    #[account(
+        mut,
+        seeds = [b"metadata", token_metadata_program.key().as_ref(), business_mint.key().as_ref()],
+        bump,
+        seeds::program = token_metadata_program.key()
+    )]
+    pub business_metadata: UncheckedAccount<'info>,
+    
+    #[account(
+        init,
+        payer = owner,
+        associated_token::mint = business_mint,
+        associated_token::authority = owner,
+        associated_token::token_program = token_program
+    )]
+    pub owner_nft_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = BusinessNft::SPACE,
+        seeds = [b"business_nft", business_mint.key().as_ref()],
+        bump
+    )]
+    pub business_nft: Account<'info, BusinessNft>,
+    
+    pub token_program: Program<'info, Token2022>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_metadata_program: Program<'info, Metadata>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Turns an owned business into an NFT held by the owner, keeping its level.
+/// The business keeps earning income for whoever holds the token.
+/// `remaining_accounts` must hold the `BusinessType` account of every owned
+/// business, in ownership order, so pending income is settled first.
+pub fn wrap_business<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WrapBusiness<'info>>,
+    business_id: u8,
+) -> Result<()> {
+    let player = &mut ctx.accounts.player_state;
+    let clock = Clock::get()?;
+    
+    settle_and_emit_income(player, ctx.remaining_accounts, clock.unix_timestamp)?;
+    
+    let level = remove_business(player, business_id)?;
+    
+    mint_business_nft(
+        &ctx.accounts.game_config,
+        &ctx.accounts.nft_config,
+        &ctx.accounts.business_mint,
+        &ctx.accounts.business_metadata,
+        &ctx.accounts.owner_nft_account,
+        &ctx.accounts.owner,
+        &ctx.accounts.token_program,
+        &ctx.accounts.token_metadata_program,
+        &ctx.accounts.system_program,
+        business_id,
+    )?;
+    
+    let record = &mut ctx.accounts.business_nft;
+    record.mint = ctx.accounts.business_mint.key();
+    record.business_id = business_id;
+    record.level = level;
+    record.minted_by = ctx.accounts.owner.key();
+    record.minted_ts = clock.unix_timestamp;
+    record.last_income_claim_ts = clock.unix_timestamp;
+    record.holder = ctx.accounts.owner.key();
+    record.bump = ctx.bumps.business_nft;
+    record.version = BusinessNft::CURRENT_VERSION;
+    
+    emit!(BusinessWrapped {
+        player: record.minted_by,
+        business_id,
+        level,
+        mint: record.mint,
+        timestamp: clock.unix_timestamp,
+    });
+    
+    Ok(())
+}
+
+/// Mints the NFT for `business_id` into a freshly created business mint.
+#[allow(clippy::too_many_arguments)]
+pub fn mint_business_nft<'info>(
+    game_config: &Account<'info, GameConfig>,
+    nft_config: &BusinessNftConfig,
+    mint: &InterfaceAccount<'info, Mint>,
+    metadata: &UncheckedAccount<'info>,
+    destination: &InterfaceAccount<'info, TokenAccount>,
+    payer: &Signer<'info>,
+    token_program: &Program<'info, Token2022>,
+    token_metadata_program: &Program<'info, Metadata>,
+    system_program: &Program<'info, System>,
+    business_id: u8,
+) -> Result<()> {
+    mint_game_nft(
+        game_config,
+        mint,
+        metadata,
+        destination,
+        payer,
+        token_program,
+        token_metadata_program,
+        system_program,
+        format!("{} #{}", BUSINESS_NFT_NAME, business_id),
+        BUSINESS_NFT_SYMBOL.to_string(),
+        format!("{}{}.json", nft_config.base_uri, business_id),
+    )
+}
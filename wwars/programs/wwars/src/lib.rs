@@ -5,6 +5,7 @@ mod state;
 mod math;
 mod errors;
 mod events;
+mod migration;
 mod instructions;
 
 declare_id!("GCg5RAMT4pk74ybPTq2E9vo1o8SK2r5bJaJeYj45eCvH");
@@ -20,8 +21,9 @@ pub mod wwars {
         instructions::initialize_game::initialize_game(ctx, params)
     }
 
-    pub fn join_game(ctx: Context<JoinGame>) -> Result<()> {
-        instructions::join_game::join_game(ctx)
+    /// Alias of `initialize_player`, kept so existing clients keep onboarding.
+    pub fn join_game(ctx: Context<InitializePlayer>) -> Result<()> {
+        instructions::initialize_player::initialize_player(ctx)
     }
 
     pub fn add_asset_class(
@@ -51,26 +53,229 @@ pub mod wwars {
         instructions::takeover::takeover(ctx, target_player, class_id)
     }
 
+    pub fn tokenize_holding(ctx: Context<TokenizeHolding>, class_id: u64) -> Result<()> {
+        instructions::tokenize_holding::tokenize_holding(ctx, class_id)
+    }
+
+    pub fn claim_holding_yield(ctx: Context<ClaimHoldingYield>, class_id: u64) -> Result<()> {
+        instructions::claim_holding_yield::claim_holding_yield(ctx, class_id)
+    }
+
     pub fn set_params(ctx: Context<SetParams>, args: SetParamsArgs) -> Result<()> {
         instructions::set_params::set_params(ctx, args)
     }
 
-    pub fn pause(ctx: Context<Pause>, paused: bool) -> Result<()> {
-        instructions::set_params::pause(ctx, paused)
+    pub fn pause(ctx: Context<Pause>, pause_flags: u16) -> Result<()> {
+        instructions::set_params::pause(ctx, pause_flags)
     }
 
     pub fn initialize_player(ctx: Context<InitializePlayer>) -> Result<()> {
         instructions::initialize_player::initialize_player(ctx)
     }
 
-    pub fn do_work(ctx: Context<DoWork>) -> Result<()> {
+    pub fn do_work<'info>(ctx: Context<'_, '_, 'info, 'info, DoWork<'info>>) -> Result<()> {
         instructions::work::do_work(ctx)
     }
 
-    pub fn purchase_business(ctx: Context<PurchaseBusiness>, business_id: u8) -> Result<()> {
+    pub fn purchase_business<'info>(ctx: Context<'_, '_, 'info, 'info, PurchaseBusiness<'info>>, business_id: u8) -> Result<()> {
         instructions::purchase_business::purchase_business(ctx, business_id)
     }
 
+    pub fn initialize_work_config(ctx: Context<InitializeWorkConfig>, tables: state::WorkTables) -> Result<()> {
+        instructions::initialize_work_config::initialize_work_config(ctx, tables)
+    }
+
+    pub fn set_work_config(ctx: Context<SetWorkConfig>, tables: state::WorkTables) -> Result<()> {
+        instructions::set_work_config::set_work_config(ctx, tables)
+    }
+
+    pub fn transfer_credits(ctx: Context<TransferCredits>, to: Pubkey, amount: u64) -> Result<()> {
+        instructions::transfer_credits::transfer_credits(ctx, to, amount)
+    }
+
+    pub fn initialize_credits_mint(ctx: Context<InitializeCreditsMint>, enabled: bool) -> Result<()> {
+        instructions::initialize_credits_mint::initialize_credits_mint(ctx, enabled)
+    }
+
+    pub fn set_credits_mint_config(ctx: Context<SetCreditsMintConfig>, enabled: bool) -> Result<()> {
+        instructions::set_credits_mint_config::set_credits_mint_config(ctx, enabled)
+    }
+
+    pub fn deposit_credits(ctx: Context<DepositCredits>, amount: u64) -> Result<()> {
+        instructions::deposit_credits::deposit_credits(ctx, amount)
+    }
+
+    pub fn withdraw_credits(ctx: Context<WithdrawCredits>, amount: u64) -> Result<()> {
+        instructions::withdraw_credits::withdraw_credits(ctx, amount)
+    }
+
+    pub fn initialize_wealth_escrow(ctx: Context<InitializeWealthEscrow>) -> Result<()> {
+        instructions::wealth_escrow::initialize_wealth_escrow(ctx)
+    }
+
+    pub fn deposit_wealth(ctx: Context<DepositWealth>, amount: u64) -> Result<()> {
+        instructions::wealth_escrow::deposit_wealth(ctx, amount)
+    }
+
+    pub fn withdraw_wealth(ctx: Context<WithdrawWealth>, amount: u64) -> Result<()> {
+        instructions::wealth_escrow::withdraw_wealth(ctx, amount)
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        id: u32,
+        start_ts: i64,
+        end_ts: i64,
+        rules: state::CarryOverRules,
+    ) -> Result<()> {
+        instructions::create_season::create_season(ctx, id, start_ts, end_ts, rules)
+    }
+
+    pub fn rollover_season<'info>(ctx: Context<'_, '_, 'info, 'info, RolloverSeason<'info>>) -> Result<()> {
+        instructions::rollover_season::rollover_season(ctx)
+    }
+
+    pub fn join_season(ctx: Context<JoinSeason>) -> Result<()> {
+        instructions::join_season::join_season(ctx)
+    }
+
+    pub fn initialize_season_pass_config(
+        ctx: Context<InitializeSeasonPassConfig>,
+        premium_price: u64,
+        free_reward: u64,
+        premium_reward: u64,
+    ) -> Result<()> {
+        instructions::initialize_season_pass_config::initialize_season_pass_config(ctx, premium_price, free_reward, premium_reward)
+    }
+
+    pub fn set_season_pass_config(
+        ctx: Context<SetSeasonPassConfig>,
+        premium_price: Option<u64>,
+        free_reward: Option<u64>,
+        premium_reward: Option<u64>,
+    ) -> Result<()> {
+        instructions::set_season_pass_config::set_season_pass_config(ctx, premium_price, free_reward, premium_reward)
+    }
+
+    pub fn open_season_pass(ctx: Context<OpenSeasonPass>) -> Result<()> {
+        instructions::open_season_pass::open_season_pass(ctx)
+    }
+
+    pub fn purchase_premium_pass(ctx: Context<PurchasePremiumPass>) -> Result<()> {
+        instructions::purchase_premium_pass::purchase_premium_pass(ctx)
+    }
+
+    pub fn claim_pass_reward(ctx: Context<ClaimPassReward>, season_id: u32, level: u8) -> Result<()> {
+        instructions::claim_pass_reward::claim_pass_reward(ctx, season_id, level)
+    }
+
+    pub fn open_achievements<'info>(ctx: Context<'_, '_, 'info, 'info, OpenAchievements<'info>>) -> Result<()> {
+        instructions::open_achievements::open_achievements(ctx)
+    }
+
+    pub fn initialize_leaderboard(ctx: Context<InitializeLeaderboard>, season_id: u32) -> Result<()> {
+        instructions::initialize_leaderboard::initialize_leaderboard(ctx, season_id)
+    }
+
+    pub fn update_rank<'info>(ctx: Context<'_, '_, 'info, 'info, UpdateRank<'info>>, player: Pubkey) -> Result<()> {
+        instructions::update_rank::update_rank(ctx, player)
+    }
+
+    pub fn create_session(ctx: Context<CreateSession>, params: CreateSessionParams) -> Result<()> {
+        instructions::create_session::create_session(ctx, params)
+    }
+
+    pub fn revoke_session(ctx: Context<RevokeSession>) -> Result<()> {
+        instructions::revoke_session::revoke_session(ctx)
+    }
+
+    pub fn set_streak_freeze_config(ctx: Context<SetStreakFreezeConfig>, price_credits: u64, price_wealth: u64, max_streak_freezes: u8) -> Result<()> {
+        instructions::set_streak_freeze_config::set_streak_freeze_config(ctx, price_credits, price_wealth, max_streak_freezes)
+    }
+
+    pub fn set_level_decay_config(ctx: Context<SetLevelDecayConfig>, mode: state::LevelDecayMode, activity_decay_bps: u16) -> Result<()> {
+        instructions::set_level_decay_config::set_level_decay_config(ctx, mode, activity_decay_bps)
+    }
+
+    pub fn buy_streak_freeze(ctx: Context<BuyStreakFreeze>, quantity: u8) -> Result<()> {
+        instructions::buy_streak_freeze::buy_streak_freeze(ctx, quantity)
+    }
+
+    pub fn buy_streak_freeze_with_wealth(ctx: Context<BuyStreakFreezeWithWealth>, quantity: u8) -> Result<()> {
+        instructions::buy_streak_freeze::buy_streak_freeze_with_wealth(ctx, quantity)
+    }
+
+    pub fn add_business_type(
+        ctx: Context<AddBusinessType>,
+        params: AddBusinessTypeParams,
+    ) -> Result<()> {
+        instructions::add_business_type::add_business_type(ctx, params)
+    }
+
+    pub fn set_business_type(ctx: Context<SetBusinessType>, business_id: u8, args: SetBusinessTypeArgs) -> Result<()> {
+        instructions::set_business_type::set_business_type(ctx, business_id, args)
+    }
+
+    pub fn claim_business_income<'info>(ctx: Context<'_, '_, 'info, 'info, ClaimBusinessIncome<'info>>) -> Result<()> {
+        instructions::claim_business_income::claim_business_income(ctx)
+    }
+
+    pub fn sell_business<'info>(ctx: Context<'_, '_, 'info, 'info, SellBusiness<'info>>, business_id: u8) -> Result<()> {
+        instructions::sell_business::sell_business(ctx, business_id)
+    }
+
+    pub fn list_business_for_sale<'info>(ctx: Context<'_, '_, 'info, 'info, ListBusinessForSale<'info>>, business_id: u8, price: u64) -> Result<()> {
+        instructions::list_business_for_sale::list_business_for_sale(ctx, business_id, price)
+    }
+
+    pub fn cancel_business_listing<'info>(ctx: Context<'_, '_, 'info, 'info, CancelBusinessListing<'info>>, business_id: u8) -> Result<()> {
+        instructions::cancel_business_listing::cancel_business_listing(ctx, business_id)
+    }
+
+    pub fn buy_business_listing<'info>(ctx: Context<'_, '_, 'info, 'info, BuyBusinessListing<'info>>, seller: Pubkey, business_id: u8) -> Result<()> {
+        instructions::buy_business_listing::buy_business_listing(ctx, seller, business_id)
+    }
+
+    pub fn initialize_business_nft_config(ctx: Context<InitializeBusinessNftConfig>, base_uri: String) -> Result<()> {
+        instructions::initialize_business_nft_config::initialize_business_nft_config(ctx, base_uri)
+    }
+
+    pub fn set_business_nft_config(ctx: Context<SetBusinessNftConfig>, enabled: Option<bool>, base_uri: Option<String>) -> Result<()> {
+        instructions::set_business_nft_config::set_business_nft_config(ctx, enabled, base_uri)
+    }
+
+    pub fn purchase_business_nft<'info>(ctx: Context<'_, '_, 'info, 'info, PurchaseBusinessNft<'info>>, business_id: u8) -> Result<()> {
+        instructions::purchase_business_nft::purchase_business_nft(ctx, business_id)
+    }
+
+    pub fn wrap_business<'info>(ctx: Context<'_, '_, 'info, 'info, WrapBusiness<'info>>, business_id: u8) -> Result<()> {
+        instructions::wrap_business::wrap_business(ctx, business_id)
+    }
+
+    pub fn unwrap_business<'info>(ctx: Context<'_, '_, 'info, 'info, UnwrapBusiness<'info>>) -> Result<()> {
+        instructions::unwrap_business::unwrap_business(ctx)
+    }
+
+    pub fn claim_business_nft_income(ctx: Context<ClaimBusinessNftIncome>) -> Result<()> {
+        instructions::claim_business_nft_income::claim_business_nft_income(ctx)
+    }
+
+    pub fn upgrade_business(ctx: Context<UpgradeBusiness>, business_id: u8) -> Result<()> {
+        instructions::upgrade_business::upgrade_business(ctx, business_id)
+    }
+
+    pub fn activate_business(ctx: Context<ManageBusinessSlots>, business_id: u8) -> Result<()> {
+        instructions::business_slots::activate_business(ctx, business_id)
+    }
+
+    pub fn deactivate_business(ctx: Context<ManageBusinessSlots>, business_id: u8) -> Result<()> {
+        instructions::business_slots::deactivate_business(ctx, business_id)
+    }
+
+    pub fn swap_active_business(ctx: Context<ManageBusinessSlots>, out_business_id: u8, in_business_id: u8) -> Result<()> {
+        instructions::business_slots::swap_active_business(ctx, out_business_id, in_business_id)
+    }
+
     pub fn initialize_treasury(ctx: Context<InitializeTreasury>, fee_bps: u16, max_trade_units: u64) -> Result<()> {
         instructions::initialize_treasury::initialize_treasury(ctx, fee_bps, max_trade_units)
     }
@@ -87,7 +292,102 @@ pub mod wwars {
         instructions::swap_wealth_for_credits::swap_wealth_for_credits(ctx, wealth_in, min_credits_out)
     }
 
-    pub fn set_treasury_params(ctx: Context<SetTreasuryParams>, fee_bps: Option<u16>, max_trade_units: Option<u64>, paused: Option<bool>) -> Result<()> {
-        instructions::set_treasury_params::set_treasury_params(ctx, fee_bps, max_trade_units, paused)
+    pub fn set_treasury_params(ctx: Context<SetTreasuryParams>, fee_bps: Option<u16>, max_trade_units: Option<u64>, guardian: Option<Pubkey>) -> Result<()> {
+        instructions::set_treasury_params::set_treasury_params(ctx, fee_bps, max_trade_units, guardian)
+    }
+
+    pub fn set_treasury_paused(ctx: Context<SetTreasuryPaused>, pause_flags: u16) -> Result<()> {
+        instructions::set_treasury_paused::set_treasury_paused(ctx, pause_flags)
+    }
+
+    pub fn initialize_revenue_config(
+        ctx: Context<InitializeRevenueConfig>,
+        params: InitializeRevenueConfigParams,
+    ) -> Result<()> {
+        instructions::initialize_revenue_config::initialize_revenue_config(ctx, params)
+    }
+
+    pub fn set_revenue_config(ctx: Context<SetRevenueConfig>, args: SetRevenueConfigArgs) -> Result<()> {
+        instructions::set_revenue_config::set_revenue_config(ctx, args)
+    }
+
+    pub fn distribute_revenue(ctx: Context<DistributeRevenue>) -> Result<()> {
+        instructions::distribute_revenue::distribute_revenue(ctx)
+    }
+
+    pub fn migrate_player(ctx: Context<MigratePlayer>) -> Result<()> {
+        instructions::migrate_player::migrate_player(ctx)
+    }
+
+    pub fn migrate_game_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::GameConfig>(ctx)
+    }
+
+    pub fn migrate_asset_class(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::AssetClass>(ctx)
+    }
+
+    pub fn migrate_holding(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::Holding>(ctx)
+    }
+
+    pub fn migrate_treasury(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::Treasury>(ctx)
+    }
+
+    pub fn migrate_revenue_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::RevenueConfig>(ctx)
+    }
+
+    pub fn migrate_business_type(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::BusinessType>(ctx)
+    }
+
+    pub fn migrate_business_listing(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::BusinessListing>(ctx)
+    }
+
+    pub fn migrate_business_nft_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::BusinessNftConfig>(ctx)
+    }
+
+    pub fn migrate_business_nft(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::BusinessNft>(ctx)
+    }
+
+    pub fn migrate_work_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::WorkConfig>(ctx)
+    }
+
+    pub fn migrate_session_key(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::SessionKey>(ctx)
+    }
+
+    pub fn migrate_credits_mint_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::CreditsMintConfig>(ctx)
+    }
+
+    pub fn migrate_season(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::Season>(ctx)
+    }
+
+    pub fn migrate_player_season_stats(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::PlayerSeasonStats>(ctx)
+    }
+
+    pub fn migrate_season_pass_config(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::SeasonPassConfig>(ctx)
+    }
+
+    pub fn migrate_season_pass(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::SeasonPass>(ctx)
+    }
+
+    pub fn migrate_achievements(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::Achievements>(ctx)
+    }
+
+    pub fn migrate_leaderboard(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_account::migrate_account::<state::Leaderboard>(ctx)
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
 use crate::state::*;
 use crate::errors::ErrorCode;
 
@@ -22,6 +23,42 @@ pub fn price_for_level(base_price: u64, num: u64, den: u64, level: u16) -> Resul
     scaled_val.try_into().map_err(|_| ErrorCode::MathOverflow.into())
 }
 
+pub fn bps_of(amount: u64, bps: u16) -> Result<u64> {
+    let share = (amount as u128)
+        .checked_mul(bps as u128)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(ErrorCode::MathOverflow)?;
+    share.try_into().map_err(|_| ErrorCode::MathOverflow.into())
+}
+
+pub fn validate_revenue_split(rewards_bps: u16, treasury_bps: u16, burn_bps: u16) -> Result<()> {
+    let total = rewards_bps as u32 + treasury_bps as u32 + burn_bps as u32;
+    require!(total == 10000, ErrorCode::InvalidRevenueSplit);
+    Ok(())
+}
+
+/// Work tables must get strictly better with each level: higher streak
+/// thresholds and shorter cooldowns, with rewards and slots never shrinking.
+pub fn validate_work_tables(tables: &WorkTables) -> Result<()> {
+    require!(tables.streak_thresholds[0] == 0, ErrorCode::InvalidWorkConfig);
+    require!(tables.base_reward > 0, ErrorCode::InvalidWorkConfig);
+    require!(tables.streak_window_secs >= 24 * 3600, ErrorCode::InvalidWorkConfig);
+    require!(tables.cooldown_hours[WORK_TIERS - 1] > 0, ErrorCode::InvalidWorkConfig);
+    require!(tables.max_slots[0] > 0, ErrorCode::InvalidWorkConfig);
+    require!(tables.max_slots[WORK_TIERS - 1] <= MAX_ACTIVE_SLOTS, ErrorCode::InvalidWorkConfig);
+
+    for i in 1..WORK_TIERS {
+        require!(
+            tables.streak_thresholds[i] > tables.streak_thresholds[i - 1]
+                && tables.cooldown_hours[i] < tables.cooldown_hours[i - 1]
+                && tables.reward_multiplier_pct[i] >= tables.reward_multiplier_pct[i - 1]
+                && tables.max_slots[i] >= tables.max_slots[i - 1],
+            ErrorCode::InvalidWorkConfig
+        );
+    }
+    Ok(())
+}
+
 pub fn takeover_cost(base_price: u64, num: u64, den: u64, level: u16) -> Result<u64> {
     // MVP: 1.25x the buy price for current level
     let buy_price = price_for_level(base_price, num, den, level)?;
@@ -61,3 +98,85 @@ pub fn update_risk(holding: &mut Holding, asset_class: &AssetClass, game_config:
         holding.last_risk_ts = now;
     }
 }
+
+/// Checks that `signer` controls the holding: the recorded player for a plain
+/// holding, or whoever holds its NFT once tokenized. For tokenized holdings
+/// `holding.player` is moved to the current holder, stamping `holder_since`.
+pub fn authorize_holding(
+    holding: &mut Holding,
+    signer: &Pubkey,
+    holder_nft_account: Option<&TokenAccount>,
+) -> Result<()> {
+    if !holding.is_tokenized() {
+        require_keys_eq!(holding.player, *signer, ErrorCode::Unauthorized);
+        return Ok(());
+    }
+
+    let nft = holder_nft_account.ok_or(ErrorCode::Unauthorized)?;
+    require!(
+        nft.mint == holding.mint && nft.owner == *signer && nft.amount == 1,
+        ErrorCode::Unauthorized
+    );
+    if holding.player != *signer {
+        holding.player = *signer;
+        holding.holder_since = Clock::get()?.unix_timestamp;
+    }
+    Ok(())
+}
+
+/// Checks that `holder` holds the NFT of the wrapped business `record`.
+pub fn authorize_business_nft(
+    record: &BusinessNft,
+    holder: &Pubkey,
+    holder_nft_account: Option<&TokenAccount>,
+) -> Result<()> {
+    let nft = holder_nft_account.ok_or(ErrorCode::NotOwned)?;
+    require!(
+        nft.mint == record.mint && nft.owner == *holder && nft.amount == 1,
+        ErrorCode::NotOwned
+    );
+    Ok(())
+}
+
+/// Checks that `signer` may act for `owner`: either it is the owner, or it
+/// holds an unexpired session allowing `action` with room left for the
+/// spends, which are then counted. Returns whether a session was used.
+pub fn authorize_player(
+    owner: &Pubkey,
+    signer: &Pubkey,
+    session: Option<&mut SessionKey>,
+    action: u16,
+    credits_spend: u64,
+    wealth_spend: u64,
+    now: i64,
+) -> Result<bool> {
+    if signer == owner {
+        return Ok(false);
+    }
+
+    let session = session.ok_or(ErrorCode::Unauthorized)?;
+    require_keys_eq!(session.owner, *owner, ErrorCode::Unauthorized);
+    require_keys_eq!(session.session_signer, *signer, ErrorCode::Unauthorized);
+    require!(now < session.expires_at, ErrorCode::SessionExpired);
+    require!(session.allowed_actions & action != 0, ErrorCode::SessionActionNotAllowed);
+
+    let credits_spent = session.credits_spent.checked_add(credits_spend).ok_or(ErrorCode::MathOverflow)?;
+    let wealth_spent = session.wealth_spent.checked_add(wealth_spend).ok_or(ErrorCode::MathOverflow)?;
+    require!(
+        credits_spent <= session.max_credits_spend && wealth_spent <= session.max_wealth_spend,
+        ErrorCode::SessionLimitExceeded
+    );
+    session.credits_spent = credits_spent;
+    session.wealth_spent = wealth_spent;
+    Ok(true)
+}
+
+/// Computes the new pause flags for a pause request. The admin may set any
+/// combination within `mask`; the guardian may only add flags.
+pub fn next_pause_flags(current: u16, requested: u16, mask: u16, is_admin: bool) -> Result<u16> {
+    require!(requested & !mask == 0, ErrorCode::InvalidParameters);
+    if !is_admin {
+        require!(requested & current == current, ErrorCode::GuardianCannotUnpause);
+    }
+    Ok(requested)
+}
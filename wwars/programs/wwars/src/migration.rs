@@ -0,0 +1,91 @@
+//! In-place schema migration for program accounts.
+//!
+//! Layouts are append-only: a new field goes after the existing ones, taking
+//! its bytes out of the trailing `reserved` array where it fits. Older data
+//! can then be zero-extended to the current size and decoded with the current
+//! struct, which reads every field it did not have yet (including `version`)
+//! as zero. Accounts that outgrow their allocation are resized with `realloc`.
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::Discriminator;
+use crate::errors::ErrorCode;
+
+pub trait Versioned: AccountSerialize + AccountDeserialize + Discriminator {
+    const CURRENT_VERSION: u8;
+    const SPACE: usize;
+
+    fn version(&self) -> u8;
+    fn set_version(&mut self, version: u8);
+
+    /// Decodes account data written by any supported layout version.
+    fn decode_any(data: &[u8]) -> Result<Self> {
+        decode_zero_extended(data)
+    }
+}
+
+pub fn decode_zero_extended<T: Versioned>(data: &[u8]) -> Result<T> {
+    let mut buf = data.to_vec();
+    if buf.len() < T::SPACE {
+        buf.resize(T::SPACE, 0);
+    }
+    T::try_deserialize(&mut &buf[..])
+}
+
+/// Loads a program-owned `T` account regardless of its layout version.
+pub fn load_any<T: Versioned>(info: &AccountInfo) -> Result<T> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::Unauthorized);
+    let data = info.try_borrow_data()?;
+    require!(
+        data.len() >= 8 && data[..8] == *T::DISCRIMINATOR,
+        anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+    );
+    T::decode_any(&data)
+}
+
+/// Writes `account` into `info`, growing it to `T::SPACE` first if needed.
+/// Any extra rent is paid by `payer`.
+pub fn store<'info, T: Versioned>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    account: &T,
+) -> Result<()> {
+    if info.data_len() < T::SPACE {
+        let required = Rent::get()?.minimum_balance(T::SPACE);
+        let top_up = required.saturating_sub(info.lamports());
+        if top_up > 0 {
+            let transfer_ctx = CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: info.clone(),
+                },
+            );
+            system_program::transfer(transfer_ctx, top_up)?;
+        }
+        info.resize(T::SPACE)?;
+    }
+
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// Upgrades `info` to the current layout of `T` and returns its old version.
+pub fn migrate_in_place<'info, T: Versioned>(
+    info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    let mut account = load_any::<T>(info)?;
+    let from_version = account.version();
+    require!(
+        from_version < T::CURRENT_VERSION || info.data_len() < T::SPACE,
+        ErrorCode::AlreadyMigrated
+    );
+
+    account.set_version(T::CURRENT_VERSION);
+    store(info, payer, system_program, &account)?;
+
+    Ok(from_version)
+}
@@ -1,12 +1,66 @@
 use anchor_lang::prelude::*;
+use crate::migration::{decode_zero_extended, Versioned};
+use crate::errors::ErrorCode;
+
+// Pause flags. Game actions live in `GameConfig::pause_flags`,
+// swap and liquidity actions in `Treasury::pause_flags`.
+pub const PAUSE_BUY_ASSET: u16 = 1 << 0;
+pub const PAUSE_UPGRADE: u16 = 1 << 1;
+pub const PAUSE_DEFEND: u16 = 1 << 2;
+pub const PAUSE_TAKEOVER: u16 = 1 << 3;
+pub const PAUSE_WORK: u16 = 1 << 4;
+pub const PAUSE_BUSINESS: u16 = 1 << 5;
+pub const PAUSE_SWAP_CREDITS_FOR_WEALTH: u16 = 1 << 6;
+pub const PAUSE_SWAP_WEALTH_FOR_CREDITS: u16 = 1 << 7;
+pub const PAUSE_LIQUIDITY: u16 = 1 << 8;
+pub const PAUSE_HOLDING_YIELD: u16 = 1 << 9;
+pub const PAUSE_CREDIT_TRANSFER: u16 = 1 << 10;
+pub const PAUSE_CREDITS_BRIDGE: u16 = 1 << 11;
+pub const PAUSE_WEALTH_ESCROW: u16 = 1 << 12;
+pub const PAUSE_SEASON_PASS: u16 = 1 << 13;
+
+pub const GAME_PAUSE_MASK: u16 = PAUSE_BUY_ASSET
+    | PAUSE_UPGRADE
+    | PAUSE_DEFEND
+    | PAUSE_TAKEOVER
+    | PAUSE_WORK
+    | PAUSE_BUSINESS
+    | PAUSE_HOLDING_YIELD
+    | PAUSE_CREDIT_TRANSFER
+    | PAUSE_CREDITS_BRIDGE
+    | PAUSE_WEALTH_ESCROW
+    | PAUSE_SEASON_PASS;
+pub const TREASURY_PAUSE_MASK: u16 = PAUSE_SWAP_CREDITS_FOR_WEALTH
+    | PAUSE_SWAP_WEALTH_FOR_CREDITS
+    | PAUSE_LIQUIDITY;
+
+// Capacity of `PlayerState::businesses_owned`
+pub const MAX_BUSINESS_TYPES: u8 = 32;
+pub const MAX_BUSINESS_LEVEL: u8 = 10;
+pub const MAX_ACTIVE_SLOTS: u8 = 5;
+
+// Actions a session key may perform for its owner
+pub const SESSION_DO_WORK: u16 = 1 << 0;
+pub const SESSION_DEFEND: u16 = 1 << 1;
+pub const SESSION_SWAP_CREDITS_FOR_WEALTH: u16 = 1 << 2;
+pub const SESSION_SWAP_WEALTH_FOR_CREDITS: u16 = 1 << 3;
+
+pub const SESSION_ACTIONS_MASK: u16 = SESSION_DO_WORK
+    | SESSION_DEFEND
+    | SESSION_SWAP_CREDITS_FOR_WEALTH
+    | SESSION_SWAP_WEALTH_FOR_CREDITS;
+pub const MAX_SESSION_DURATION: i64 = 7 * 24 * 3600;
+pub const WORK_TIERS: usize = 5;
 
 #[account]
+#[derive(InitSpace)]
 pub struct GameConfig {
     pub admin: Pubkey,
     pub wealth_mint: Pubkey,
     pub treasury_vault: Pubkey,
+    pub guardian: Pubkey,
     pub fee_bps: u16,
-    pub paused: bool,
+    pub pause_flags: u16,
     pub bump_config: u8,
     pub bump_vault: u8,
     pub default_upgrade_cd: i64,
@@ -14,12 +68,115 @@ pub struct GameConfig {
     pub risk_threshold: u32,
     pub risk_growth_per_sec: u32,
     pub defend_risk_reduction_per_token: u32,
+    pub burn_bps: u16,        // Share of every WEALTH spend that is burned
+    pub total_burned: u64,    // Lifetime WEALTH burned by the program
+    pub version: u8,
+    pub attestation_mode: AttestationMode,   // Proof of presence required by do_work
+    pub attestation_authority: Pubkey,       // Co-signer or Ed25519 signer for attestations
+    pub credit_transfer_fee_bps: u16,        // Burned from the sender on top of the amount
+    pub credit_transfer_daily_limit: u64,    // Per sender per UTC day; 0 disables transfers
+    pub credit_transfer_min_age_secs: i64,   // Minimum sender account age
+    pub bump_wealth_escrow: u8,              // `[b"wealth_escrow", config]`, 0 until initialized
+    pub current_season: u32,                 // Id of the live `Season`, 0 before the first
+    pub reserved: [u8; 8],
+}
+
+/// How `do_work` proves a human is present.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum AttestationMode {
+    None,
+    CoSigner, // `attestation_authority` signs the transaction
+    Ed25519,  // An Ed25519 verify instruction signed by `attestation_authority`
 }
 
 impl GameConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_paused(&self, flag: u16) -> bool {
+        self.pause_flags & flag != 0
+    }
+}
+
+impl Versioned for GameConfig {
+    // v2: work attestation, v3: credit transfers, v4: WEALTH escrow,
+    // v5: seasons
+    const CURRENT_VERSION: u8 = 5;
+    const SPACE: usize = GameConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn decode_any(data: &[u8]) -> Result<Self> {
+        // v0 has `paused: bool` and no guardian ahead of the later fields,
+        // so it cannot be zero-extended
+        if data.len() == GameConfigV0::SPACE {
+            let v0 = GameConfigV0::deserialize(&mut &data[8..])
+                .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+            return Ok(v0.into());
+        }
+        decode_zero_extended(data)
+    }
+}
+
+/// `GameConfig` layout before versioning. Only decoded by `migrate_game_config`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct GameConfigV0 {
+    pub admin: Pubkey,
+    pub wealth_mint: Pubkey,
+    pub treasury_vault: Pubkey,
+    pub fee_bps: u16,
+    pub paused: bool,
+    pub bump_config: u8,
+    pub bump_vault: u8,
+    pub default_upgrade_cd: i64,
+    pub default_defend_cd: i64,
+    pub risk_threshold: u32,
+    pub risk_growth_per_sec: u32,
+    pub defend_risk_reduction_per_token: u32,
+}
+
+impl GameConfigV0 {
     pub const SPACE: usize = 8 + 32 + 32 + 32 + 2 + 1 + 1 + 1 + 8 + 8 + 4 + 4 + 4;
 }
 
+impl From<GameConfigV0> for GameConfig {
+    fn from(v0: GameConfigV0) -> Self {
+        Self {
+            admin: v0.admin,
+            wealth_mint: v0.wealth_mint,
+            treasury_vault: v0.treasury_vault,
+            guardian: v0.admin,
+            fee_bps: v0.fee_bps,
+            pause_flags: if v0.paused { GAME_PAUSE_MASK } else { 0 },
+            bump_config: v0.bump_config,
+            bump_vault: v0.bump_vault,
+            default_upgrade_cd: v0.default_upgrade_cd,
+            default_defend_cd: v0.default_defend_cd,
+            risk_threshold: v0.risk_threshold,
+            risk_growth_per_sec: v0.risk_growth_per_sec,
+            defend_risk_reduction_per_token: v0.defend_risk_reduction_per_token,
+            burn_bps: 0,
+            total_burned: 0,
+            version: 0,
+            attestation_mode: AttestationMode::None,
+            attestation_authority: Pubkey::default(),
+            credit_transfer_fee_bps: 0,
+            credit_transfer_daily_limit: 0,
+            credit_transfer_min_age_secs: 0,
+            bump_wealth_escrow: 0,
+            current_season: 0,
+            reserved: [0; 8],
+        }
+    }
+}
+
+/// Legacy PvP player account (`[b"player", authority]`). Superseded by
+/// `PlayerState`; only read and closed by `migrate_player`.
 #[account]
 pub struct Player {
     pub authority: Pubkey,
@@ -32,26 +189,800 @@ impl Player {
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct PlayerState {
+    pub version: u8,                      // Layout version, see Versioned
     pub owner: Pubkey,                    // Player's wallet
     pub last_work_timestamp: i64,         // Unix timestamp from Solana Clock
     pub streak_count: u32,                // Consecutive work days
     pub work_frequency_level: u8,         // 0=novice, 4=master (24h→12h→6h)
     pub total_work_actions: u64,          // Lifetime work count
     pub credits: u64,                     // On-chain credit balance
-    pub wealth_tokens: u64,               // $WEALTH token balance
+    pub wealth_tokens: u64,               // $WEALTH escrowed in the `wealth_escrow` vault
+    #[max_len(32)]
     pub businesses_owned: Vec<u8>,        // Business IDs owned
+    #[max_len(5)]
     pub active_business_slots: Vec<u8>,   // Currently active businesses
     pub last_streak_check: i64,           // For streak validation window
     pub cooldown_hours: u8,               // Current cooldown (24→12→6)
+    pub last_defend_ts: i64,              // Last defend across all holdings
     pub bump: u8,                         // PDA bump seed
+    pub business_levels: [u8; 32],        // Upgrade level per business ID
+    pub last_income_claim_ts: i64,        // Start of the passive income window
+    pub streak_freezes: u8,               // Consumed to bridge missed streak windows
+    pub activity_score: u32,              // Decaying work-day count, in hundredths of a day
+    pub created_ts: i64,                  // 0 for accounts created before v7
+    pub transfer_day: u32,                // UTC day of `credits_sent_today`
+    pub credits_sent_today: u64,
+    pub season_id: u32,                   // Last season whose carry-over was applied
+    pub holdings_count: u16,              // Untokenized holdings tracked since v9; may trail older ones
+    pub nft_business_mask: u32,           // Active slots backed by a business NFT, one bit per business ID
+    pub bridged_credits: u64,             // SPL credits paid out to this player that the program takes back
+    pub reserved: [u8; 33],
 }
 
 impl PlayerState {
+    pub const MAX_SIZE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn init(&mut self, owner: Pubkey, bump: u8, now: i64, season_id: u32) {
+        self.version = Self::CURRENT_VERSION;
+        self.owner = owner;
+        self.last_work_timestamp = 0;
+        self.streak_count = 0;
+        self.work_frequency_level = 0; // Start as novice
+        self.total_work_actions = 0;
+        self.credits = 1000; // Starting credits
+        self.wealth_tokens = 0;
+        self.businesses_owned = Vec::new();
+        self.active_business_slots = Vec::new();
+        self.last_streak_check = now;
+        self.cooldown_hours = 24; // Start with 24h cooldown
+        self.last_defend_ts = now;
+        self.bump = bump;
+        self.business_levels = [0; 32];
+        self.last_income_claim_ts = now;
+        self.streak_freezes = 0;
+        self.activity_score = 0;
+        self.created_ts = now;
+        self.transfer_day = 0;
+        self.credits_sent_today = 0;
+        self.season_id = season_id;
+        self.holdings_count = 0;
+        self.nft_business_mask = 0;
+        self.bridged_credits = 0;
+        self.reserved = [0; 33];
+    }
+
+    /// Whether the carry-over of `current_season` has been applied. Players
+    /// go through `rollover_season` before acting in a new season.
+    pub fn in_season(&self, current_season: u32) -> bool {
+        self.season_id >= current_season
+    }
+
+    /// Whether the active slot of `business_id` is backed by a business NFT
+    /// rather than `businesses_owned`.
+    pub fn is_nft_slot(&self, business_id: u8) -> bool {
+        self.nft_business_mask & (1 << business_id) != 0
+    }
+
+    pub fn set_nft_slot(&mut self, business_id: u8, nft: bool) {
+        if nft {
+            self.nft_business_mask |= 1 << business_id;
+        } else {
+            self.nft_business_mask &= !(1 << business_id);
+        }
+    }
+
+    /// Counts `amount` SPL credits from this player toward what the program
+    /// paid out to them. Tokens received from other wallets are not covered,
+    /// so they cannot get around `transfer_credits`.
+    pub fn take_bridged_credits(&mut self, amount: u64) -> Result<()> {
+        self.bridged_credits = self
+            .bridged_credits
+            .checked_sub(amount)
+            .ok_or(ErrorCode::BridgedCreditsExceeded)?;
+        Ok(())
+    }
+
+    /// Checks `transfer_credits`' account age and daily limit for `amount`
+    /// credits leaving this player, and counts it toward today's total.
+    /// Returns the total sent today.
+    pub fn count_credits_sent(&mut self, config: &GameConfig, amount: u64, now: i64) -> Result<u64> {
+        require!(config.credit_transfer_daily_limit > 0, ErrorCode::CreditTransfersDisabled);
+
+        // Accounts from before creation times were tracked count as old enough
+        let age = now.saturating_sub(self.created_ts);
+        require!(age >= config.credit_transfer_min_age_secs, ErrorCode::AccountTooYoung);
+
+        let today = (now / (24 * 3600)) as u32;
+        if self.transfer_day != today {
+            self.transfer_day = today;
+            self.credits_sent_today = 0;
+        }
+        let sent_today = self.credits_sent_today.checked_add(amount).ok_or(ErrorCode::MathOverflow)?;
+        require!(sent_today <= config.credit_transfer_daily_limit, ErrorCode::DailyTransferLimit);
+        self.credits_sent_today = sent_today;
+        Ok(sent_today)
+    }
+}
+
+impl Versioned for PlayerState {
+    // v1: merged legacy `Player`, v2: reserved bytes, v3: business levels,
+    // v4: passive income, v5: streak freezes, v6: activity score,
+    // v7: credit transfers, v8: seasons, v9: holdings count,
+    // v10: NFT business slots, v11: bridged credits
+    const CURRENT_VERSION: u8 = 11;
+    const SPACE: usize = PlayerState::MAX_SIZE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn decode_any(data: &[u8]) -> Result<Self> {
+        // v0 has no leading version byte, so it cannot be zero-extended
+        if data.len() == PlayerStateV0::MAX_SIZE {
+            let v0 = PlayerStateV0::deserialize(&mut &data[8..])
+                .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+            return Ok(v0.into());
+        }
+        decode_zero_extended(data)
+    }
+}
+
+/// `PlayerState` layout before versioning. Only decoded by `migrate_player`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlayerStateV0 {
+    pub owner: Pubkey,
+    pub last_work_timestamp: i64,
+    pub streak_count: u32,
+    pub work_frequency_level: u8,
+    pub total_work_actions: u64,
+    pub credits: u64,
+    pub wealth_tokens: u64,
+    pub businesses_owned: Vec<u8>,
+    pub active_business_slots: Vec<u8>,
+    pub last_streak_check: i64,
+    pub cooldown_hours: u8,
+    pub bump: u8,
+}
+
+impl PlayerStateV0 {
     pub const MAX_SIZE: usize = 8 + 32 + 8 + 4 + 1 + 8 + 8 + 8 + (4 + 20) + (4 + 5) + 8 + 1 + 1;
 }
 
+impl From<PlayerStateV0> for PlayerState {
+    fn from(v0: PlayerStateV0) -> Self {
+        Self {
+            version: 0,
+            owner: v0.owner,
+            last_work_timestamp: v0.last_work_timestamp,
+            streak_count: v0.streak_count,
+            work_frequency_level: v0.work_frequency_level,
+            total_work_actions: v0.total_work_actions,
+            credits: v0.credits,
+            wealth_tokens: v0.wealth_tokens,
+            businesses_owned: v0.businesses_owned,
+            active_business_slots: v0.active_business_slots,
+            last_streak_check: v0.last_streak_check,
+            cooldown_hours: v0.cooldown_hours,
+            last_defend_ts: 0,
+            bump: v0.bump,
+            business_levels: [0; 32],
+            last_income_claim_ts: 0,
+            streak_freezes: 0,
+            activity_score: 0,
+            created_ts: 0,
+            transfer_day: 0,
+            credits_sent_today: 0,
+            season_id: 0,
+            holdings_count: 0,
+            nft_business_mask: 0,
+            bridged_credits: 0,
+            reserved: [0; 33],
+        }
+    }
+}
+
+/// What players keep when entering a season. `credits_keep_bps` also scales
+/// `PlayerState::bridged_credits`, so withdrawing to SPL before the rollover
+/// does not keep more credits playable: SPL tokens beyond the kept share can
+/// no longer be deposited or spent in the game.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct CarryOverRules {
+    pub credits_keep_bps: u16,     // Share of credits kept, 10000 keeps everything
+    pub level_drop: u8,            // Work levels lost; the streak drops to the new level's threshold
+    pub clear_streak_freezes: bool,
+}
+
+/// One entry of the on-chain calendar, at `[b"season", id]`. Ids start at 1
+/// and `rollover_season` moves `GameConfig::current_season` to the next one
+/// once it has started.
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub id: u32,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub rules: CarryOverRules,     // Applied to players as they enter this season
+    pub players_rolled: u32,       // PlayerStates carried over into this season
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl Season {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for Season {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = Season::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// A player's record for one season, at `[b"season_stats", season_id, player]`.
+/// Actions only count while the season's window is open.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerSeasonStats {
+    pub season_id: u32,
+    pub player: Pubkey,
+    pub start_ts: i64,             // Copied from the season
+    pub end_ts: i64,
+    pub work_actions: u32,
+    pub credits_earned: u64,
+    pub takeovers: u32,
+    pub wealth_earned: u64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl PlayerSeasonStats {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_live(&self, now: i64) -> bool {
+        now >= self.start_ts && now < self.end_ts
+    }
+}
+
+impl Versioned for PlayerSeasonStats {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = PlayerSeasonStats::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+pub const LEADERBOARD_SIZE: usize = 25;
+// Entries not refreshed for this long no longer rank. It also bounds how long
+// a transferred NFT or WEALTH can still count for its previous holder.
+pub const LEADERBOARD_STALE_SECS: i64 = 24 * 60 * 60;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct LeaderboardEntry {
+    pub player: Pubkey,
+    pub net_worth: u64,
+    pub updated_ts: i64,
+}
+
+impl LeaderboardEntry {
+    pub fn is_stale(&self, now: i64) -> bool {
+        now.saturating_sub(self.updated_ts) >= LEADERBOARD_STALE_SECS
+    }
+}
+
+/// A season's top players by net worth, at `[b"leaderboard", season_id]`,
+/// sorted highest first. Refreshed per player by `update_rank`. Readers should
+/// skip stale entries (see `standings`); they are dropped on the next update.
 #[account]
+#[derive(InitSpace)]
+pub struct Leaderboard {
+    pub season_id: u32,
+    #[max_len(25)]
+    pub entries: Vec<LeaderboardEntry>,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl Leaderboard {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// Entries refreshed within `LEADERBOARD_STALE_SECS`, highest first.
+    pub fn standings(&self, now: i64) -> impl Iterator<Item = &LeaderboardEntry> {
+        self.entries.iter().filter(move |entry| !entry.is_stale(now))
+    }
+
+    /// `player`'s entry, if it is on the board and not stale.
+    pub fn fresh_entry(&self, player: &Pubkey, now: i64) -> Option<&LeaderboardEntry> {
+        self.standings(now).find(|entry| entry.player == *player)
+    }
+
+    /// Replaces `player`'s entry, drops stale ones and keeps the board sorted
+    /// and capped. Returns the player's zero-based rank, or `None` if they
+    /// fell off.
+    pub fn record(&mut self, player: Pubkey, net_worth: u64, now: i64) -> Option<u8> {
+        self.entries.retain(|entry| entry.player != player && !entry.is_stale(now));
+
+        let rank = self.entries.partition_point(|entry| entry.net_worth >= net_worth);
+        if rank >= LEADERBOARD_SIZE {
+            return None;
+        }
+        self.entries.insert(rank, LeaderboardEntry { player, net_worth, updated_ts: now });
+        self.entries.truncate(LEADERBOARD_SIZE);
+        Some(rank as u8)
+    }
+}
+
+impl Versioned for Leaderboard {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = Leaderboard::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+// Achievement bits of `Achievements::unlocked`
+pub const ACH_FIRST_BUSINESS: u8 = 0;
+pub const ACH_STREAK_7: u8 = 1;
+pub const ACH_STREAK_30: u8 = 2;
+pub const ACH_FIRST_TAKEOVER: u8 = 3;
+pub const ACH_SUCCESSFUL_DEFENSE: u8 = 4;   // Defend brings an at-risk holding below the threshold
+pub const ACH_WORK_ACTIONS: u8 = 5;
+pub const ACH_HOLDING_LEVEL: u8 = 6;
+pub const ACH_WORK_ACTIONS_REQUIRED: u64 = 100;
+pub const ACH_HOLDING_LEVEL_REQUIRED: u16 = 5;
+// One-time credit bonus per achievement, indexed by bit
+pub const ACHIEVEMENT_BONUS_CREDITS: [u64; 7] = [100, 250, 1000, 250, 100, 500, 500];
+
+/// A player's unlocked achievements, at `[b"achievements", player]`.
+/// Instructions that reach a milestone set its bit when this is passed.
+#[account]
+#[derive(InitSpace)]
+pub struct Achievements {
+    pub player: Pubkey,
+    pub unlocked: u64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl Achievements {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_unlocked(&self, achievement: u8) -> bool {
+        self.unlocked & (1 << achievement) != 0
+    }
+}
+
+impl Versioned for Achievements {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = Achievements::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+// Season pass progression
+pub const PASS_LEVELS: u8 = 100;
+pub const XP_PER_PASS_LEVEL: u64 = 1000;
+pub const XP_DO_WORK: u64 = 100;
+pub const XP_BUY_ASSET: u64 = 250;
+pub const XP_DEFEND: u64 = 50;
+pub const XP_TAKEOVER: u64 = 300;
+
+/// Season pass pricing and rewards, at `[b"season_pass_config"]`. Rewards are
+/// paid in WEALTH from the vault at `[b"pass_reward_vault", config]`.
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonPassConfig {
+    pub premium_price: u64,           // WEALTH; 0 stops premium sales
+    pub free_reward: u64,             // WEALTH paid per free track level
+    pub premium_reward: u64,          // WEALTH paid per premium track level
+    pub reward_vault: Pubkey,
+    pub bump: u8,
+    pub bump_reward_vault: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl SeasonPassConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for SeasonPassConfig {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = SeasonPassConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// A player's pass for one season, at `[b"season_pass", season_id, player]`.
+/// XP only accrues while the season is live; rewards can be claimed later.
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonPass {
+    pub season_id: u32,
+    pub player: Pubkey,
+    pub start_ts: i64,                // Copied from the season
+    pub end_ts: i64,
+    pub xp: u64,
+    pub premium: bool,
+    pub claimed_free: [u8; 13],       // Bit per level, level 1 is bit 0
+    pub claimed_premium: [u8; 13],
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl SeasonPass {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn level(&self) -> u8 {
+        (self.xp / XP_PER_PASS_LEVEL).min(PASS_LEVELS as u64) as u8
+    }
+
+    pub fn add_xp(&mut self, xp: u64, now: i64) {
+        if now >= self.start_ts && now < self.end_ts {
+            self.xp = self.xp.saturating_add(xp);
+        }
+    }
+
+    pub fn is_claimed(claimed: &[u8; 13], level: u8) -> bool {
+        let bit = (level - 1) as usize;
+        claimed[bit / 8] & (1 << (bit % 8)) != 0
+    }
+
+    pub fn set_claimed(claimed: &mut [u8; 13], level: u8) {
+        let bit = (level - 1) as usize;
+        claimed[bit / 8] |= 1 << (bit % 8);
+    }
+}
+
+impl Versioned for SeasonPass {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = SeasonPass::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// A delegated signer for frequent actions, at
+/// `[b"session", owner, session_signer]`. WEALTH spends go through the
+/// session PDA as delegate of the owner's token account.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKey {
+    pub owner: Pubkey,
+    pub session_signer: Pubkey,
+    pub expires_at: i64,
+    pub allowed_actions: u16,         // SESSION_* bits
+    pub max_credits_spend: u64,
+    pub credits_spent: u64,
+    pub max_wealth_spend: u64,
+    pub wealth_spent: u64,
+    pub created_ts: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl SessionKey {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for SessionKey {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = SessionKey::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// Work progression tables, one entry per work frequency level.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct WorkTables {
+    pub streak_thresholds: [u32; WORK_TIERS], // Streak days needed to reach each level
+    pub cooldown_hours: [u8; WORK_TIERS],
+    pub reward_multiplier_pct: [u16; WORK_TIERS],
+    pub max_slots: [u8; WORK_TIERS],          // Active business slots
+    pub base_reward: u64,                     // Credits per work action before bonuses
+    pub streak_window_secs: i64,              // Max gap between work days before the streak breaks
+    pub streak_bonus_per_day: u64,
+    pub streak_bonus_cap_days: u32,           // Streak days beyond this earn no extra bonus
+}
+
+/// How far the work level falls when a streak breaks.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum LevelDecayMode {
+    Reset,            // Back to level 0
+    OneTierPerWindow, // One level per missed streak window
+    ActivityScore,    // The level the player's activity score reaches
+}
+
+/// Admin-tunable work loop settings, at `[b"work_config"]`.
+#[account]
+#[derive(InitSpace)]
+pub struct WorkConfig {
+    pub tables: WorkTables,
+    pub bump: u8,
+    pub version: u8,
+    pub streak_freeze_price_credits: u64, // 0 = not sold for credits
+    pub streak_freeze_price_wealth: u64,  // 0 = not sold for WEALTH
+    pub max_streak_freezes: u8,           // Most freezes a player may hold
+    pub level_decay_mode: LevelDecayMode,
+    pub activity_decay_bps: u16,          // Activity score lost per work day or missed window
+    pub reserved: [u8; 44],
+}
+
+impl WorkConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// Highest level whose streak threshold `streak_count` reaches.
+    pub fn level_for_streak(&self, streak_count: u32) -> u8 {
+        self.tables
+            .streak_thresholds
+            .iter()
+            .rposition(|threshold| streak_count >= *threshold)
+            .unwrap_or(0) as u8
+    }
+
+    pub fn cooldown_hours(&self, level: u8) -> u8 {
+        self.tables.cooldown_hours[Self::tier(level)]
+    }
+
+    pub fn reward_multiplier_pct(&self, level: u8) -> u16 {
+        self.tables.reward_multiplier_pct[Self::tier(level)]
+    }
+
+    pub fn max_slots(&self, level: u8) -> u8 {
+        self.tables.max_slots[Self::tier(level)]
+    }
+
+    fn tier(level: u8) -> usize {
+        (level as usize).min(WORK_TIERS - 1)
+    }
+}
+
+impl Versioned for WorkConfig {
+    // v2: streak freeze pricing, v3: level decay
+    const CURRENT_VERSION: u8 = 3;
+    const SPACE: usize = WorkConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// Catalog entry for one business, at `[b"business", &[business_id]]`.
+#[account]
+#[derive(InitSpace)]
+pub struct BusinessType {
+    pub business_id: u8,
+    pub cost: u64,                    // Purchase price in credits
+    pub name_hash: [u8; 32],          // sha256 of the display name
+    pub work_multiplier_bps: u16,     // Added to the work reward multiplier while active
+    pub passive_income_per_hour: u64, // Credits accrued per hour while owned
+    pub required_level: u8,           // Minimum work_frequency_level to purchase
+    pub enabled: bool,                // Disabled types cannot be purchased
+    pub bump: u8,
+    pub version: u8,
+    pub flat_bonus: u64,              // Credits added to each work reward while active
+    pub set_id: u8,                   // Related businesses share a set (0 = none)
+    pub set_bonus_bps: u16,           // Added to the multiplier while its set bonus is met
+    pub reserved: [u8; 53],
+}
+
+impl BusinessType {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for BusinessType {
+    // v2: flat bonus and set bonus
+    const CURRENT_VERSION: u8 = 2;
+    const SPACE: usize = BusinessType::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// A business held in escrow while listed for sale,
+/// at `[b"listing", seller, &[business_id]]`.
+#[account]
+#[derive(InitSpace)]
+pub struct BusinessListing {
+    pub seller: Pubkey,
+    pub business_id: u8,
+    pub level: u8,                    // Upgrade level carried over to the buyer
+    pub price: u64,                   // WEALTH
+    pub created_ts: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 32],
+}
+
+impl BusinessListing {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for BusinessListing {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = BusinessListing::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// Opt-in SPL representation of credits, at `[b"credits_mint_config"]`.
+/// The mint at `[b"credits_mint"]` is controlled by this account; balances
+/// move between it and `PlayerState::credits` through `deposit_credits` and
+/// `withdraw_credits`. While enabled, `do_work`, business purchases and swaps
+/// also earn and spend SPL credits directly when the player passes their
+/// credits token account. When the treasury's `quote_mint` is this mint,
+/// swaps keep `quote_vault` backed so its reserve is real.
+#[account]
+#[derive(InitSpace)]
+pub struct CreditsMintConfig {
+    pub mint: Pubkey,
+    pub enabled: bool,                // Gates the bridge and SPL earning and spending; swaps keep the vault in sync regardless
+    pub total_withdrawn: u64,         // Credits moved out of the ledger into SPL tokens
+    pub total_deposited: u64,         // SPL tokens burned back into the ledger
+    pub bump: u8,
+    pub mint_bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl CreditsMintConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    /// The credits mint PDA, at `[b"credits_mint"]`.
+    pub fn mint_address() -> Pubkey {
+        Pubkey::find_program_address(&[b"credits_mint"], &crate::ID).0
+    }
+}
+
+impl Versioned for CreditsMintConfig {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = CreditsMintConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+pub const MAX_NFT_URI_LEN: usize = 128;
+
+/// Settings for tokenized businesses, at `[b"business_nft_config"]`.
+#[account]
+#[derive(InitSpace)]
+pub struct BusinessNftConfig {
+    pub enabled: bool,                // Gates wrapping and NFT purchases; unwrapping always works
+    #[max_len(128)]
+    pub base_uri: String,             // Metadata URI is `{base_uri}{business_id}.json`, or `{base_uri}holding-{class_id}.json`
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl BusinessNftConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for BusinessNftConfig {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = BusinessNftConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+/// A business held as a supply-1 Token-2022 NFT, at `[b"business_nft", mint]`.
+/// Whoever holds the token collects its income and can unwrap it back into
+/// their `PlayerState`.
+#[account]
+#[derive(InitSpace)]
+pub struct BusinessNft {
+    pub mint: Pubkey,
+    pub business_id: u8,
+    pub level: u8,
+    pub minted_by: Pubkey,
+    pub minted_ts: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub last_income_claim_ts: i64,    // Start of the unclaimed income window
+    pub reserved: [u8; 24],
+    pub holder: Pubkey,               // Player `update_rank` last saw holding the NFT
+    pub holder_since: i64,            // When `holder` last changed
+}
+
+impl BusinessNft {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for BusinessNft {
+    // v2: income window
+    // v3: ranked holder
+    const CURRENT_VERSION: u8 = 3;
+    const SPACE: usize = BusinessNft::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
 pub struct AssetClass {
     pub class_id: u64,
     pub base_price: u64,
@@ -62,13 +993,29 @@ pub struct AssetClass {
     pub defend_cd: i64,
     pub base_risk_growth_per_sec: u32,
     pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
 }
 
 impl AssetClass {
-    pub const SPACE: usize = 8 + 8 + 8 + 8 + 8 + 8 + 8 + 8 + 4 + 1;
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for AssetClass {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = AssetClass::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Holding {
     pub player: Pubkey,
     pub class: Pubkey,
@@ -80,13 +1027,40 @@ pub struct Holding {
     pub last_risk_ts: i64,
     pub risk_score: u32,
     pub bump: u8,
+    pub version: u8,
+    pub mint: Pubkey,                 // NFT mint once tokenized, default otherwise
+    pub mint_nonce: u8,               // Bumped each time a takeover detaches the NFT
+    pub level_achievement_granted: bool, // Set once this holding has paid out ACH_HOLDING_LEVEL
+    pub holder_since: i64,            // When `player` last changed hands
+    pub reserved: [u8; 22],
 }
 
 impl Holding {
-    pub const SPACE: usize = 8 + 32 + 32 + 2 + 4 + 8 + 8 + 8 + 8 + 4 + 1;
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_tokenized(&self) -> bool {
+        self.mint != Pubkey::default()
+    }
+}
+
+impl Versioned for Holding {
+    // v2: NFT mint
+    // v3: level achievement flag
+    // v4: holder change time
+    const CURRENT_VERSION: u8 = 4;
+    const SPACE: usize = Holding::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
 }
 
 #[account]
+#[derive(InitSpace)]
 pub struct Treasury {
     pub bump: u8,
     pub base_mint: Pubkey,        // WEALTH token mint
@@ -95,13 +1069,121 @@ pub struct Treasury {
     pub quote_vault: Pubkey,      // Credits/USDC vault
     pub fee_bps: u16,            // 0-1000 (0-10%)
     pub max_trade_units: u64,     // Anti-whale cap
-    pub paused: bool,
+    pub pause_flags: u16,         // PAUSE_SWAP_* / PAUSE_LIQUIDITY bits
     pub min_base_liquidity: u64,  // Minimum WEALTH in pool
     pub min_quote_liquidity: u64, // Minimum Credits in pool
     pub last_params_slot: u64,    // Governance hygiene
     pub params_authority: Pubkey, // Who can change params
+    pub guardian: Pubkey,         // Can pause, cannot unpause
+    pub version: u8,
+    pub reserved: [u8; 64],
 }
 
 impl Treasury {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+
+    pub fn is_paused(&self, flag: u16) -> bool {
+        self.pause_flags & flag != 0
+    }
+}
+
+impl Versioned for Treasury {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = Treasury::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+
+    fn decode_any(data: &[u8]) -> Result<Self> {
+        // v0 has `paused: bool` where `pause_flags` now is, so it cannot be
+        // zero-extended
+        if data.len() == TreasuryV0::SPACE {
+            let v0 = TreasuryV0::deserialize(&mut &data[8..])
+                .map_err(|_| anchor_lang::error::ErrorCode::AccountDidNotDeserialize)?;
+            return Ok(v0.into());
+        }
+        decode_zero_extended(data)
+    }
+}
+
+/// `Treasury` layout before versioning. Only decoded by `migrate_treasury`.
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct TreasuryV0 {
+    pub bump: u8,
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+    pub base_vault: Pubkey,
+    pub quote_vault: Pubkey,
+    pub fee_bps: u16,
+    pub max_trade_units: u64,
+    pub paused: bool,
+    pub min_base_liquidity: u64,
+    pub min_quote_liquidity: u64,
+    pub last_params_slot: u64,
+    pub params_authority: Pubkey,
+}
+
+impl TreasuryV0 {
     pub const SPACE: usize = 8 + 1 + 32 + 32 + 32 + 32 + 2 + 8 + 1 + 8 + 8 + 8 + 32;
 }
+
+impl From<TreasuryV0> for Treasury {
+    fn from(v0: TreasuryV0) -> Self {
+        Self {
+            bump: v0.bump,
+            base_mint: v0.base_mint,
+            quote_mint: v0.quote_mint,
+            base_vault: v0.base_vault,
+            quote_vault: v0.quote_vault,
+            fee_bps: v0.fee_bps,
+            max_trade_units: v0.max_trade_units,
+            pause_flags: if v0.paused { TREASURY_PAUSE_MASK } else { 0 },
+            min_base_liquidity: v0.min_base_liquidity,
+            min_quote_liquidity: v0.min_quote_liquidity,
+            last_params_slot: v0.last_params_slot,
+            params_authority: v0.params_authority,
+            guardian: v0.params_authority,
+            version: 0,
+            reserved: [0; 64],
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RevenueConfig {
+    pub game_config: Pubkey,
+    pub rewards_vault: Pubkey,        // Player rewards pool (WEALTH)
+    pub treasury_destination: Pubkey, // Team/ops treasury (WEALTH)
+    pub rewards_bps: u16,
+    pub treasury_bps: u16,
+    pub burn_bps: u16,
+    pub total_distributed: u64,       // Lifetime amount routed out of the game vault
+    pub total_burned: u64,            // Lifetime amount burned by the router
+    pub last_distribution_ts: i64,
+    pub bump: u8,
+    pub version: u8,
+    pub reserved: [u8; 64],
+}
+
+impl RevenueConfig {
+    pub const SPACE: usize = 8 + Self::INIT_SPACE;
+}
+
+impl Versioned for RevenueConfig {
+    const CURRENT_VERSION: u8 = 1;
+    const SPACE: usize = RevenueConfig::SPACE;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn set_version(&mut self, version: u8) {
+        self.version = version;
+    }
+}